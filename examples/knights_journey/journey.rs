@@ -1,5 +1,3 @@
-use std::fmt::{self, Display, Formatter};
-
 use backtracking::Problem;
 
 use super::{
@@ -29,21 +27,22 @@ impl Journey {
             start,
         }
     }
-}
 
-impl Display for Solution {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0[0])?;
-        for m in &self.0[1..NUM_FIELDS] {
-            write!(f, " {m}")?;
-        }
-        Ok(())
+    /// Number of unvisited fields the knight could move on to from `field`.
+    pub fn onward_moves(&self, field: Position) -> usize {
+        let mut moves = Vec::new();
+        self.board.reachable_fields(field, &mut moves);
+        moves
+            .iter()
+            .filter(|pos| !self.visited[pos.as_index()])
+            .count()
     }
 }
 
 impl Problem for Journey {
     type Posibility = Position;
-    type Solution = Solution;
+    /// The moves are the history leading to the solution, so there is nothing else to extract.
+    type Solution = ();
 
     fn extend_possibilities(&self, possible_moves: &mut Vec<Position>, _history: &[Position]) {
         self.board.reachable_fields(self.current, possible_moves);
@@ -60,15 +59,7 @@ impl Problem for Journey {
         self.visited[next.as_index()] = true;
     }
 
-    fn is_solution(&self, history: &[Position]) -> Option<Solution> {
-        if history.len() == NUM_FIELDS - 1 {
-            let mut moves = [self.start; NUM_FIELDS];
-            moves[1..].copy_from_slice(history);
-            Some(Solution(moves))
-        } else {
-            None
-        }
+    fn is_solution(&self, history: &[Position]) -> Option<()> {
+        (history.len() == NUM_FIELDS - 1).then_some(())
     }
 }
-
-pub struct Solution([Position; NUM_FIELDS]);
@@ -1,7 +1,7 @@
 //! The knights journey is a puzzle. A knight starts in the upper left corner (A1) of a chessboard
 //! and visits each field exactly once.
 
-use backtracking::Solutions;
+use backtracking::{Solutions, VisitOrder};
 use journey::Journey;
 use position::Position;
 
@@ -15,8 +15,18 @@ const NUM_SOLUTIONS: usize = 1000;
 fn main() {
     let start = Position::new(0, 0);
     let journey = Journey::new(start);
-    for (index, solution) in Solutions::new(journey).enumerate().take(NUM_SOLUTIONS) {
+    // Warnsdorff's rule: Move to the field with the fewest onward moves first. Without it, finding
+    // the first journey takes a very long time.
+    let order = VisitOrder::by_key(|journey: &Journey, &field| journey.onward_moves(field));
+    let solutions = Solutions::new(journey)
+        .with_visit_order(order)
+        .with_history();
+    for (index, ((), moves)) in solutions.enumerate().take(NUM_SOLUTIONS) {
         let num_solution = index + 1;
-        println!("#{num_solution}: {solution}")
+        print!("#{num_solution}: {start}");
+        for m in moves {
+            print!(" {m}");
+        }
+        println!()
     }
 }
@@ -1,11 +1,13 @@
 use std::fmt::{self, Display, Formatter};
 
-use backtracking::{Problem, Solutions};
+use backtracking::{Canonical, Problem, Symmetric};
 
 fn main() {
     let board = NQueens::new(8);
-    for solution in Solutions::new(board) {
-        println!("{solution}")
+    // Only print the fundamental solutions. All others can be obtained by rotating or mirroring the
+    // board.
+    for (solution, orbit_size) in Canonical::new(board).with_orbit_size() {
+        println!("Stands for {orbit_size} solutions:\n{solution}")
     }
 }
 
@@ -71,7 +73,40 @@ impl Problem for NQueens {
     }
 }
 
+impl Symmetric for NQueens {
+    fn images(&self, solution: &NQueensSolution) -> Vec<NQueensSolution> {
+        let m = self.n - 1;
+        // Each bit of `symmetry` stands for mirroring columns, mirroring rows or transposing.
+        // Together these generate all rotations and reflections of the board.
+        (0..8)
+            .map(|symmetry| {
+                let mut image = vec![0; self.n as usize];
+                for (row, &column) in solution.0.iter().enumerate() {
+                    let (mut row, mut column) = (row as u32, column);
+                    if symmetry & 1 != 0 {
+                        column = m - column;
+                    }
+                    if symmetry & 2 != 0 {
+                        row = m - row;
+                    }
+                    if symmetry & 4 != 0 {
+                        (row, column) = (column, row);
+                    }
+                    image[row as usize] = column;
+                }
+                NQueensSolution(image)
+            })
+            .collect()
+    }
+
+    fn is_redundant_root(&self, first: &QueenAt) -> bool {
+        // Mirroring the columns would yield a solution starting with a smaller column.
+        2 * first.column > self.n - 1
+    }
+}
+
 /// Solution to the n queens problem. Nth index of vec contains column index of queen in n-th row.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
 struct NQueensSolution(Vec<u32>);
 
 impl Display for NQueensSolution {
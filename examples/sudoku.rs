@@ -1,4 +1,7 @@
-use std::io::{self, stdout, Write};
+use std::{
+    io::{self, stdout, Write},
+    ops::ControlFlow,
+};
 
 use backtracking::{Problem, Solutions};
 
@@ -9,10 +12,15 @@ fn main() -> io::Result<()> {
         0, 1, 0, 0, 0, 7, 4, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 5, 3, 0, 1, 0, 0, 0, 0,
         0, 4, 0, 0, 0, 6, 3, 0, 7, 0, 9, 0, 0, 9, 0, 0, 0, 2, 0, 3, 0,
     ]);
-    for solution in Solutions::new(sudoku).take(1) {
-        solution.print_to(&mut stdout())?
+    // We only print the first solution. Borrowing it from the search, rather than having it
+    // returned by the iterator, avoids cloning the board.
+    let printed = Solutions::new(sudoku).for_each_solution(|solution, _history| {
+        ControlFlow::Break(solution.print_to(&mut stdout()))
+    });
+    match printed {
+        ControlFlow::Break(result) => result,
+        ControlFlow::Continue(()) => Ok(()),
     }
-    Ok(())
 }
 
 #[derive(Clone)]
@@ -132,13 +140,17 @@ impl Problem for Sudoku {
         self.fields[move_.index as usize] = move_.digit;
     }
 
-    fn is_solution(&self, _history: &[WriteDigit]) -> Option<Self::Solution> {
-        if self.fields.iter().all(|digit| *digit != 0) {
+    fn is_solution(&self, history: &[WriteDigit]) -> Option<Self::Solution> {
+        if self.is_solved(history) {
             Some(self.clone())
         } else {
             None
         }
     }
+
+    fn is_solved(&self, _history: &[WriteDigit]) -> bool {
+        self.fields.iter().all(|digit| *digit != 0)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,210 @@
+use core::cmp::Ordering;
+
+use crate::{Problem, Solutions, Stats, Step};
+
+/// A problem for which we are interested in the best solution, rather than every solution. Used by
+/// [`BranchAndBound`].
+///
+/// Larger values are better. For minimization problems you may use e.g. [`core::cmp::Reverse`] or
+/// negate the value.
+pub trait Optimize: Problem {
+    /// Measure for the quality of a solution. E.g. the total value of the items packed into a
+    /// knapsack.
+    type Value: PartialOrd;
+
+    /// Quality of a solution. Called with solutions found from the current state.
+    fn objective(&self, solution: &Self::Solution) -> Self::Value;
+
+    /// Optimistic estimate for the quality of any solution which can be reached from the current
+    /// state. Must never be smaller than the objective of any such solution, otherwise the best
+    /// solution may be pruned. The closer the bound, the more of the search tree can be pruned.
+    fn bound(&self, history: &[Self::Posibility]) -> Self::Value;
+}
+
+/// Iterator performing a branch and bound search. Each solution yielded is strictly better than the
+/// previous one, so the last one is optimal.
+///
+/// The best solution found so far is called the incumbent. Once there is an incumbent, any node
+/// whose bound is not larger than the objective of the incumbent is neither checked for being a
+/// solution, nor are its children explored.
+pub struct BranchAndBound<P: Optimize> {
+    solutions: Solutions<P>,
+    incumbent: Option<P::Value>,
+}
+
+impl<P: Optimize> BranchAndBound<P> {
+    pub fn new(init: P) -> Self {
+        Self {
+            solutions: Solutions::new(init),
+            incumbent: None,
+        }
+    }
+
+    /// Objective value of the best solution found so far.
+    pub fn incumbent(&self) -> Option<&P::Value> {
+        self.incumbent.as_ref()
+    }
+
+    /// Counters describing the work done by the search so far.
+    pub fn stats(&self) -> &Stats {
+        self.solutions.stats()
+    }
+
+    /// `true` if no solution reachable from the current state could beat the incumbent.
+    fn is_hopeless(&self) -> bool {
+        match &self.incumbent {
+            Some(incumbent) => {
+                let bound = self.solutions.current.bound(&self.solutions.history);
+                // Not using `<=`, since we would also like to prune if the values are incomparable,
+                // e.g. NaN.
+                bound.partial_cmp(incumbent) != Some(Ordering::Greater)
+            }
+            None => false,
+        }
+    }
+}
+
+impl<P: Optimize> Iterator for BranchAndBound<P> {
+    type Item = P::Solution;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(candidate) = self.solutions.open.pop() {
+            self.solutions.enter(candidate);
+            if self.is_hopeless() {
+                self.solutions.stats.pruned += 1;
+                continue;
+            }
+            if let Step::Solution(solution) = self.solutions.evaluate() {
+                let value = self.solutions.current.objective(&solution);
+                let is_improvement = self
+                    .incumbent
+                    .as_ref()
+                    .is_none_or(|incumbent| value > *incumbent);
+                if is_improvement {
+                    self.incumbent = Some(value);
+                    return Some(solution);
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Problem, Solutions};
+
+    use super::{BranchAndBound, Optimize};
+
+    /// Pack items, given as `(weight, value)`, into a knapsack, maximizing the total value. Each
+    /// decision is whether to pack the next item.
+    struct Knapsack {
+        items: Vec<(u32, u32)>,
+        capacity: u32,
+        /// Index of the next item to decide about
+        next: usize,
+        weight: u32,
+        value: u32,
+    }
+
+    impl Knapsack {
+        fn new(items: Vec<(u32, u32)>, capacity: u32) -> Self {
+            Self {
+                items,
+                capacity,
+                next: 0,
+                weight: 0,
+                value: 0,
+            }
+        }
+    }
+
+    impl Problem for Knapsack {
+        type Posibility = bool;
+        type Solution = Vec<bool>;
+
+        fn extend_possibilities(&self, possibilities: &mut Vec<bool>, _history: &[bool]) {
+            let Some(&(weight, _)) = self.items.get(self.next) else {
+                return;
+            };
+            possibilities.push(false);
+            if self.weight + weight <= self.capacity {
+                possibilities.push(true);
+            }
+        }
+
+        fn undo(&mut self, last: &bool, _history: &[bool]) {
+            self.next -= 1;
+            if *last {
+                let (weight, value) = self.items[self.next];
+                self.weight -= weight;
+                self.value -= value;
+            }
+        }
+
+        fn what_if(&mut self, decision: bool) {
+            if decision {
+                let (weight, value) = self.items[self.next];
+                self.weight += weight;
+                self.value += value;
+            }
+            self.next += 1;
+        }
+
+        fn is_solution(&self, history: &[bool]) -> Option<Vec<bool>> {
+            (history.len() == self.items.len()).then(|| history.to_vec())
+        }
+    }
+
+    impl Optimize for Knapsack {
+        type Value = u32;
+
+        fn objective(&self, _solution: &Vec<bool>) -> u32 {
+            self.value
+        }
+
+        fn bound(&self, _history: &[bool]) -> u32 {
+            let remaining: u32 = self.items[self.next..].iter().map(|(_, v)| v).sum();
+            self.value + remaining
+        }
+    }
+
+    #[test]
+    fn yields_strictly_improving_solutions() {
+        let items = vec![(5, 10), (4, 40), (6, 30), (3, 50)];
+        let values = BranchAndBound::new(Knapsack::new(items.clone(), 10))
+            .map(|solution| value_of(&items, &solution))
+            .collect::<Vec<_>>();
+
+        assert!(values.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(Some(&90), values.last());
+    }
+
+    #[test]
+    fn explores_fewer_nodes_than_exhaustive_search() {
+        let items = vec![(5, 10), (4, 40), (6, 30), (3, 50), (2, 5), (7, 60)];
+        let mut exhaustive = Solutions::new(Knapsack::new(items.clone(), 12));
+        let best = exhaustive
+            .by_ref()
+            .map(|solution| value_of(&items, &solution))
+            .max();
+
+        let mut bnb = BranchAndBound::new(Knapsack::new(items.clone(), 12));
+        let last = bnb
+            .by_ref()
+            .last()
+            .map(|solution| value_of(&items, &solution));
+
+        assert_eq!(best, last);
+        assert!(bnb.stats().nodes < exhaustive.stats().nodes);
+    }
+
+    fn value_of(items: &[(u32, u32)], solution: &[bool]) -> u32 {
+        items
+            .iter()
+            .zip(solution)
+            .filter(|(_, &packed)| packed)
+            .map(|(&(_, value), _)| value)
+            .sum()
+    }
+}
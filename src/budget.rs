@@ -0,0 +1,66 @@
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+use crate::Stats;
+
+/// Outcome of [`crate::Solutions::next_solution`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SearchResult<S> {
+    /// The search found another solution.
+    Solution(S),
+    /// There are no more solutions. The entire (possibly depth limited) search tree has been
+    /// explored.
+    Exhausted,
+    /// The search stopped before finding another solution or exhausting the search tree.
+    Interrupted(Interruption),
+}
+
+/// Reason for a search to be interrupted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interruption {
+    /// The deadline has passed.
+    Deadline,
+    /// The maximum number of nodes has been entered.
+    NodeBudget,
+    /// The cancellation flag has been set.
+    Cancelled,
+}
+
+/// Conditions under which a search is interrupted. By default the search is never interrupted.
+#[derive(Clone, Debug, Default)]
+pub struct Budget {
+    #[cfg(feature = "std")]
+    pub deadline: Option<Instant>,
+    pub max_nodes: Option<u64>,
+    #[cfg(target_has_atomic = "ptr")]
+    pub cancel: Option<Arc<AtomicBool>>,
+}
+
+impl Budget {
+    /// Reason to interrupt the search, if any.
+    pub fn check(&self, stats: &Stats) -> Option<Interruption> {
+        #[cfg(target_has_atomic = "ptr")]
+        if self
+            .cancel
+            .as_ref()
+            .is_some_and(|cancel| cancel.load(Ordering::Relaxed))
+        {
+            return Some(Interruption::Cancelled);
+        }
+        if self.max_nodes.is_some_and(|max| stats.nodes >= max) {
+            return Some(Interruption::NodeBudget);
+        }
+        #[cfg(feature = "std")]
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            return Some(Interruption::Deadline);
+        }
+        None
+    }
+}
@@ -0,0 +1,133 @@
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use crate::Problem;
+
+/// Wraps a problem and checks that [`Problem::undo`] exactly reverses [`Problem::what_if`]. The
+/// correctness of the search depends on it, yet it is easy to get wrong. Intended to be used in
+/// tests.
+///
+/// Before each decision the state of the problem is cloned. After the decision is undone, the state
+/// is compared with the clone. On a mismatch this panics, reporting the offending history together
+/// with both states.
+#[derive(Clone, Debug)]
+pub struct Checked<P> {
+    inner: P,
+    /// State before each decision in the history.
+    snapshots: Vec<P>,
+}
+
+impl<P> Checked<P> {
+    pub fn new(inner: P) -> Self {
+        Self {
+            inner,
+            snapshots: Vec::new(),
+        }
+    }
+
+    /// The wrapped problem in its current state.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+impl<P> Problem for Checked<P>
+where
+    P: Problem + Clone + PartialEq + Debug,
+    P::Posibility: Debug,
+{
+    type Posibility = P::Posibility;
+    type Solution = P::Solution;
+
+    fn extend_possibilities(
+        &self,
+        possibilities: &mut Vec<Self::Posibility>,
+        history: &[Self::Posibility],
+    ) {
+        self.inner.extend_possibilities(possibilities, history)
+    }
+
+    fn undo(&mut self, last: &Self::Posibility, history: &[Self::Posibility]) {
+        self.inner.undo(last, history);
+        let expected = self
+            .snapshots
+            .pop()
+            .expect("undo must not be called more often than what_if");
+        if self.inner != expected {
+            panic!(
+                "Undoing the last decision did not restore the previous state.\n\
+                History: {history:?}\n\
+                Last decision: {last:?}\n\
+                Expected state: {expected:?}\n\
+                Actual state: {:?}",
+                self.inner
+            );
+        }
+    }
+
+    fn what_if(&mut self, decision: Self::Posibility) {
+        self.snapshots.push(self.inner.clone());
+        self.inner.what_if(decision)
+    }
+
+    fn is_solution(&self, history: &[Self::Posibility]) -> Option<Self::Solution> {
+        self.inner.is_solution(history)
+    }
+
+    fn is_solved(&self, history: &[Self::Posibility]) -> bool {
+        self.inner.is_solved(history)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        test_problems::{Queens, Stairs},
+        Problem, Solutions,
+    };
+
+    use super::Checked;
+
+    #[test]
+    fn correct_problem_passes() {
+        assert_eq!(4, Solutions::new(Checked::new(Queens::new(6))).count());
+        assert_eq!(5, Solutions::new(Checked::new(Stairs::new(4))).count());
+    }
+
+    /// Like [`Stairs`], but forgets to undo steps of length two.
+    #[derive(Clone, Debug, PartialEq)]
+    struct Forgetful(u32);
+
+    impl Problem for Forgetful {
+        type Posibility = u32;
+        type Solution = ();
+
+        fn extend_possibilities(&self, possibilities: &mut Vec<u32>, _history: &[u32]) {
+            possibilities.extend([1, 2].into_iter().filter(|&step| step <= self.0));
+        }
+
+        fn undo(&mut self, last: &u32, _history: &[u32]) {
+            if *last == 1 {
+                self.0 += 1;
+            }
+        }
+
+        fn what_if(&mut self, decision: u32) {
+            self.0 -= decision;
+        }
+
+        fn is_solution(&self, _history: &[u32]) -> Option<()> {
+            (self.0 == 0).then_some(())
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "History: [1, 1]\nLast decision: 2\nExpected state: Forgetful(2)")]
+    fn report_incorrect_undo() {
+        Solutions::new(Checked::new(Forgetful(4))).for_each(drop);
+    }
+}
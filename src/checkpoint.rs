@@ -0,0 +1,129 @@
+use alloc::vec::Vec;
+
+use crate::{Candidate, Observer, Problem, Solutions, Stats};
+
+/// Snapshot of the progress of a [`Solutions`] iterator. Together with the initial problem it is
+/// sufficient to resume the search exactly where it has been left, e.g. after a process restart.
+/// Enable the `serde` feature in order to serialize it into the format of your choice.
+///
+/// Deadlines, node budgets, cancellation flags, the transposition table and the visit order are not
+/// part of the checkpoint and need to be configured again on the resumed iterator. Use
+/// [`Solutions::resume_with_visit_order`] to restore the visit order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Checkpoint<P> {
+    open: Vec<Candidate<P>>,
+    history: Vec<P>,
+    stats: Stats,
+    max_depth: usize,
+    cut_off: bool,
+    /// Missing in checkpoints created before solutions could be expanded.
+    #[cfg_attr(feature = "serde", serde(default))]
+    expand_solutions: bool,
+}
+
+impl<P: Problem, O: Observer<P>> Solutions<P, O> {
+    /// Captures the current state of the search.
+    pub fn checkpoint(&self) -> Checkpoint<P::Posibility> {
+        Checkpoint {
+            open: self.open.clone(),
+            history: self.history.clone(),
+            stats: self.stats,
+            max_depth: self.max_depth,
+            cut_off: self.cut_off,
+            expand_solutions: self.expand_solutions,
+        }
+    }
+}
+
+impl<P: Problem> Solutions<P> {
+    /// Resumes a search from a checkpoint. `init` must be in the same state as the problem the
+    /// original [`Solutions`] iterator has been created with. The history of the checkpoint is
+    /// replayed using [`Problem::what_if`] to recreate the state the search has been in.
+    pub fn from_checkpoint(mut init: P, checkpoint: Checkpoint<P::Posibility>) -> Self {
+        let Checkpoint {
+            open,
+            history,
+            stats,
+            max_depth,
+            cut_off,
+            expand_solutions,
+        } = checkpoint;
+        for &decision in &history {
+            init.what_if(decision);
+        }
+        // Which subtrees have been cut off is not part of the checkpoint, so assume the worst.
+        let cut_off_depth = if cut_off { history.len() } else { 0 };
+        Self {
+            open,
+            history,
+            stats,
+            max_depth,
+            cut_off,
+            cut_off_depth,
+            expand_solutions,
+            ..Self::new_empty(init)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        test_problems::{Queens, Subsets},
+        Solutions, VisitOrder,
+    };
+
+    #[test]
+    fn resume_from_checkpoint() {
+        let mut solutions = Solutions::new(Queens::new(6));
+        let first = solutions.next().unwrap();
+
+        let checkpoint = solutions.checkpoint();
+        let mut resumed = Solutions::from_checkpoint(Queens::new(6), checkpoint);
+
+        assert_eq!(
+            solutions.by_ref().collect::<Vec<_>>(),
+            resumed.by_ref().collect::<Vec<_>>()
+        );
+        assert_eq!(solutions.stats(), resumed.stats());
+        assert_ne!(Some(first), resumed.next());
+    }
+
+    #[test]
+    fn resume_expanding_solutions() {
+        let mut solutions = Solutions::new(Subsets::new(4)).with_expanded_solutions();
+        solutions.next();
+
+        let resumed = Solutions::from_checkpoint(Subsets::new(4), solutions.checkpoint());
+
+        assert_eq!(14, resumed.count());
+    }
+
+    #[test]
+    fn resume_with_visit_order() {
+        let mut solutions = Solutions::new(Subsets::new(4))
+            .with_visit_order(VisitOrder::Reversed)
+            .with_expanded_solutions();
+        // Stop at a node with more than one unvisited child.
+        assert_eq!(Some(vec![1]), solutions.by_ref().nth(3));
+
+        let resumed = Solutions::from_checkpoint(Subsets::new(4), solutions.checkpoint())
+            .resume_with_visit_order(VisitOrder::Reversed);
+
+        assert_eq!(solutions.collect::<Vec<_>>(), resumed.collect::<Vec<_>>());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn checkpoint_roundtrip_through_json() {
+        let mut solutions = Solutions::new(Queens::new(6));
+        solutions.next();
+
+        let json = serde_json::to_vec(&solutions.checkpoint()).unwrap();
+        let checkpoint = serde_json::from_slice(&json).unwrap();
+        let resumed = Solutions::from_checkpoint(Queens::new(6), checkpoint);
+
+        assert_eq!(3, resumed.count());
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{Problem, Solutions};
+
+/// Iterator finding solutions by repeatedly running a depth limited search, raising the limit by
+/// one each time. Solutions are yielded in order of increasing history length, so the shortest
+/// solutions are found first, even if the problem has very deep branches without any solution.
+///
+/// The price for this is that the shallow parts of the search tree are explored again in each run.
+/// A fresh clone of the initial problem is used for each run.
+pub struct IterativeDeepening<P: Problem> {
+    init: P,
+    /// Current depth limit. Only solutions with exactly this history length are yielded by the
+    /// current run, shorter ones have already been yielded by previous runs.
+    depth: usize,
+    solutions: Solutions<P>,
+}
+
+impl<P: Problem + Clone> IterativeDeepening<P> {
+    pub fn new(init: P) -> Self {
+        let depth = 1;
+        let solutions = Solutions::new(init.clone()).with_max_depth(depth);
+        Self {
+            init,
+            depth,
+            solutions,
+        }
+    }
+
+    /// The depth limit of the current run.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+}
+
+impl<P: Problem + Clone> Iterator for IterativeDeepening<P> {
+    type Item = P::Solution;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(solution) = self.solutions.next() {
+                if self.solutions.history.len() == self.depth {
+                    return Some(solution);
+                }
+                continue;
+            }
+            // Run is exhausted. If nothing has been cut off, a deeper run would not find anything
+            // new.
+            if !self.solutions.is_cut_off() {
+                return None;
+            }
+            self.depth += 1;
+            self.solutions = Solutions::new(self.init.clone()).with_max_depth(self.depth);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_problems::Stairs, Solutions};
+
+    use super::IterativeDeepening;
+
+    #[test]
+    fn shortest_solutions_first() {
+        let stairs = Stairs::new(4);
+
+        let lengths = IterativeDeepening::new(stairs.clone())
+            .map(|solution| solution.len())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![2, 3, 3, 3, 4], lengths);
+        assert_eq!(5, Solutions::new(stairs).count());
+    }
+}
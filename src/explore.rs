@@ -0,0 +1,151 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{Frontier, Problem, Stats};
+
+/// An iterator finding solutions to a problem, visiting the search tree in the order determined by
+/// a [`Frontier`].
+///
+/// Unlike [`crate::Solutions`] the next node to visit is not necessarily a child of the current
+/// node or of one of its ancestors. To get to the state of the next node, the history is undone up
+/// to the longest prefix it shares with the path of the next node. The remaining decisions of the
+/// path are then replayed using [`Problem::what_if`]. Depending on the frontier, this may cause
+/// considerably more calls to these methods than a depth first search, and each node in the
+/// frontier stores its entire path. [`Stats::nodes`] only counts the nodes visited, not the
+/// replayed decisions.
+pub struct Explore<P: Problem, F> {
+    frontier: F,
+    decisions: Vec<P::Posibility>,
+    history: Vec<P::Posibility>,
+    current: P,
+    stats: Stats,
+}
+
+impl<P, F> Explore<P, F>
+where
+    P: Problem,
+    P::Posibility: PartialEq,
+    F: Frontier<P>,
+{
+    pub fn new(init: P, mut frontier: F) -> Self {
+        let mut decisions = Vec::new();
+        init.extend_possibilities(&mut decisions, &[]);
+        for &decision in &decisions {
+            frontier.push(&init, vec![decision]);
+        }
+        let stats = Stats {
+            max_open: frontier.len(),
+            ..Stats::default()
+        };
+        Self {
+            frontier,
+            decisions,
+            history: Vec::new(),
+            current: init,
+            stats,
+        }
+    }
+
+    /// Counters describing the work done by the search so far.
+    pub fn stats(&self) -> &Stats {
+        &self.stats
+    }
+
+    /// Changes the current state to the node at the end of `path`.
+    fn go_to(&mut self, path: &[P::Posibility]) {
+        // Even if the path equals the history, we want to enter its last node again.
+        let common = self
+            .history
+            .iter()
+            .zip(&path[..path.len() - 1])
+            .take_while(|(a, b)| a == b)
+            .count();
+        while self.history.len() > common {
+            let last = self.history.pop().unwrap();
+            self.current.undo(&last, &self.history);
+            self.stats.backtracks += 1;
+        }
+        for &decision in &path[common..] {
+            self.current.what_if(decision);
+            self.history.push(decision);
+        }
+    }
+}
+
+impl<P, F> Iterator for Explore<P, F>
+where
+    P: Problem,
+    P::Posibility: PartialEq,
+    F: Frontier<P>,
+{
+    type Item = P::Solution;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(path) = self.frontier.pop() {
+            self.go_to(&path);
+            self.stats.nodes += 1;
+            self.stats.max_depth = self.stats.max_depth.max(self.history.len());
+
+            if let Some(solution) = self.current.is_solution(&self.history) {
+                self.stats.solutions += 1;
+                return Some(solution);
+            }
+
+            self.decisions.clear();
+            self.current
+                .extend_possibilities(&mut self.decisions, &self.history);
+            if self.decisions.is_empty() {
+                self.stats.dead_ends += 1;
+            }
+            for &decision in &self.decisions {
+                let mut child = path.clone();
+                child.push(decision);
+                self.frontier.push(&self.current, child);
+            }
+            self.stats.max_open = self.stats.max_open.max(self.frontier.len());
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        test_problems::{Queens, Stairs},
+        BestFirst, BreadthFirst, DepthFirst, Solutions,
+    };
+
+    use super::Explore;
+
+    #[test]
+    fn depth_first_matches_solutions() {
+        let expected = Solutions::new(Queens::new(6)).collect::<Vec<_>>();
+
+        let actual = Explore::new(Queens::new(6), DepthFirst::new()).collect::<Vec<_>>();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn breadth_first_finds_shortest_solutions_first() {
+        let solutions = Explore::new(Stairs::new(4), BreadthFirst::new()).collect::<Vec<_>>();
+
+        let expected = vec![
+            vec![2, 2],
+            vec![1, 1, 2],
+            vec![1, 2, 1],
+            vec![2, 1, 1],
+            vec![1, 1, 1, 1],
+        ];
+        assert_eq!(expected, solutions);
+    }
+
+    #[test]
+    fn best_first_follows_highest_score() {
+        // Prefer the path which climbed the most stairs.
+        let frontier = BestFirst::new(|_: &Stairs, path: &[u32]| path.iter().sum::<u32>());
+        let mut explore = Explore::new(Stairs::new(4), frontier);
+
+        assert_eq!(Some(vec![2, 2]), explore.next());
+        assert_eq!(2, explore.stats().nodes);
+    }
+}
@@ -0,0 +1,214 @@
+use alloc::vec::Vec;
+
+use crate::{Candidate, Stats};
+
+/// Like [`crate::Problem`], but exploring the problem may fail. E.g. because it depends on
+/// external resources. Used by the [`TrySolutions`] iterator.
+pub trait TryProblem {
+    /// See [`crate::Problem::Posibility`].
+    type Posibility: Copy;
+    /// See [`crate::Problem::Solution`].
+    type Solution;
+    /// Reason for the search to fail.
+    type Error;
+
+    /// See [`crate::Problem::extend_possibilities`]. Possibilities added before an error is
+    /// returned are ignored.
+    fn extend_possibilities(
+        &self,
+        possibilities: &mut Vec<Self::Posibility>,
+        history: &[Self::Posibility],
+    ) -> Result<(), Self::Error>;
+
+    /// See [`crate::Problem::undo`]. Undo is infallible, so the problem can always be brought back
+    /// into a state consistent with the history.
+    fn undo(&mut self, last: &Self::Posibility, history: &[Self::Posibility]);
+
+    /// See [`crate::Problem::what_if`]. In case of an error the state must be left unchanged, i.e.
+    /// as if the decision had never been made.
+    fn what_if(&mut self, decision: Self::Posibility) -> Result<(), Self::Error>;
+
+    /// See [`crate::Problem::is_solution`].
+    fn is_solution(
+        &self,
+        history: &[Self::Posibility],
+    ) -> Result<Option<Self::Solution>, Self::Error>;
+}
+
+/// An iterator performing backtracking to find solutions to a [`TryProblem`]. Works like
+/// [`crate::Solutions`], but yields `Result`s. The search stops at the first error. Afterwards the
+/// state of the problem still corresponds to [`TrySolutions::history`].
+pub struct TrySolutions<P: TryProblem> {
+    decisions: Vec<P::Posibility>,
+    open: Vec<Candidate<P::Posibility>>,
+    history: Vec<P::Posibility>,
+    current: P,
+    stats: Stats,
+    /// `false` until the possibilities of the initial state have been put into the open list.
+    started: bool,
+    /// `true` after an error has been yielded.
+    failed: bool,
+}
+
+impl<P: TryProblem> TrySolutions<P> {
+    pub fn new(init: P) -> Self {
+        Self {
+            decisions: Vec::new(),
+            open: Vec::new(),
+            history: Vec::new(),
+            current: init,
+            stats: Stats::default(),
+            started: false,
+            failed: false,
+        }
+    }
+
+    /// Counters describing the work done by the search so far.
+    pub fn stats(&self) -> &Stats {
+        &self.stats
+    }
+
+    /// Decisions leading from the initial state to the current one.
+    pub fn history(&self) -> &[P::Posibility] {
+        &self.history
+    }
+
+    /// The problem in its current state.
+    pub fn problem(&self) -> &P {
+        &self.current
+    }
+
+    fn try_next(&mut self) -> Result<Option<P::Solution>, P::Error> {
+        if !self.started {
+            self.started = true;
+            self.extend()?;
+        }
+        while let Some(Candidate {
+            count,
+            possibility: mov,
+        }) = self.open.pop()
+        {
+            while self.history.len() >= count {
+                let last = self.history.pop().unwrap();
+                self.current.undo(&last, &self.history);
+                self.stats.backtracks += 1;
+            }
+
+            self.current.what_if(mov)?;
+            self.history.push(mov);
+            self.stats.nodes += 1;
+            self.stats.max_depth = self.stats.max_depth.max(self.history.len());
+
+            if let Some(solution) = self.current.is_solution(&self.history)? {
+                self.stats.solutions += 1;
+                return Ok(Some(solution));
+            }
+
+            self.extend()?;
+        }
+        Ok(None)
+    }
+
+    /// Puts the children of the current node into the open list.
+    fn extend(&mut self) -> Result<(), P::Error> {
+        let count = self.history.len();
+        self.decisions.clear();
+        self.current
+            .extend_possibilities(&mut self.decisions, &self.history)?;
+        if self.decisions.is_empty() {
+            self.stats.dead_ends += 1;
+        }
+        // Reverse, so the first possibility is popped first.
+        self.open
+            .extend(self.decisions.iter().rev().map(|&possibility| Candidate {
+                count: count + 1,
+                possibility,
+            }));
+        self.stats.max_open = self.stats.max_open.max(self.open.len());
+        Ok(())
+    }
+}
+
+impl<P: TryProblem> Iterator for TrySolutions<P> {
+    type Item = Result<P::Solution, P::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        match self.try_next() {
+            Ok(solution) => solution.map(Ok),
+            Err(error) => {
+                self.failed = true;
+                self.open.clear();
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TryProblem, TrySolutions};
+
+    /// Counts up in steps of one or two, but fails to enter a state which is a multiple of
+    /// `unlucky`.
+    struct Unlucky {
+        value: u32,
+        target: u32,
+        unlucky: u32,
+    }
+
+    impl TryProblem for Unlucky {
+        type Posibility = u32;
+        type Solution = Vec<u32>;
+        type Error = u32;
+
+        fn extend_possibilities(
+            &self,
+            possibilities: &mut Vec<u32>,
+            _history: &[u32],
+        ) -> Result<(), u32> {
+            possibilities.extend(
+                [1, 2]
+                    .into_iter()
+                    .filter(|step| self.value + step <= self.target),
+            );
+            Ok(())
+        }
+
+        fn undo(&mut self, last: &u32, _history: &[u32]) {
+            self.value -= last;
+        }
+
+        fn what_if(&mut self, decision: u32) -> Result<(), u32> {
+            let value = self.value + decision;
+            if value.is_multiple_of(self.unlucky) {
+                return Err(value);
+            }
+            self.value = value;
+            Ok(())
+        }
+
+        fn is_solution(&self, history: &[u32]) -> Result<Option<Vec<u32>>, u32> {
+            Ok((self.value == self.target).then(|| history.to_vec()))
+        }
+    }
+
+    #[test]
+    fn stop_at_first_error() {
+        let problem = Unlucky {
+            value: 0,
+            target: 5,
+            unlucky: 4,
+        };
+        let mut solutions = TrySolutions::new(problem);
+
+        // 1, 1, 1 is followed by trying to step to 4
+        assert_eq!(Some(Err(4)), solutions.next());
+        assert_eq!(None, solutions.next());
+        // State of the problem is consistent with history
+        assert_eq!(&[1, 1, 1], solutions.history());
+        assert_eq!(3, solutions.problem().value);
+    }
+}
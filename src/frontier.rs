@@ -0,0 +1,173 @@
+use alloc::{
+    collections::{BinaryHeap, VecDeque},
+    vec::Vec,
+};
+use core::cmp::{Ordering, Reverse};
+
+use crate::Problem;
+
+/// Nodes of the search tree which have been discovered, but not been explored yet. The order in
+/// which nodes are popped from the frontier determines the order in which [`crate::Explore`] visits
+/// the search tree.
+///
+/// Each node is identified by its path, i.e. the decisions leading from the initial state to the
+/// node, including the one leading to the node itself.
+pub trait Frontier<P: Problem> {
+    /// Adds a node to the frontier. `parent` is the state of the problem after the decisions in
+    /// `path`, except the last one.
+    fn push(&mut self, parent: &P, path: Vec<P::Posibility>);
+
+    /// Removes the node to be explored next from the frontier.
+    fn pop(&mut self) -> Option<Vec<P::Posibility>>;
+
+    /// Number of nodes in the frontier.
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Explore the most recently discovered node first. Same order as [`crate::Solutions`].
+#[derive(Clone, Debug)]
+pub struct DepthFirst<D> {
+    stack: Vec<Vec<D>>,
+    /// Position the next path is inserted at. Siblings pushed between two pops are stacked in
+    /// reverse, so the first child pushed is also the first one popped.
+    insert_at: usize,
+}
+
+impl<D> DepthFirst<D> {
+    pub fn new() -> Self {
+        Self {
+            stack: Vec::new(),
+            insert_at: 0,
+        }
+    }
+}
+
+impl<D> Default for DepthFirst<D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Problem> Frontier<P> for DepthFirst<P::Posibility> {
+    fn push(&mut self, _parent: &P, path: Vec<P::Posibility>) {
+        self.stack.insert(self.insert_at, path)
+    }
+
+    fn pop(&mut self) -> Option<Vec<P::Posibility>> {
+        let path = self.stack.pop();
+        self.insert_at = self.stack.len();
+        path
+    }
+
+    fn len(&self) -> usize {
+        self.stack.len()
+    }
+}
+
+/// Explore the node discovered first. All nodes of one depth are visited before any node of the
+/// next depth, so solutions are found in order of increasing history length.
+#[derive(Clone, Debug)]
+pub struct BreadthFirst<D>(VecDeque<Vec<D>>);
+
+impl<D> BreadthFirst<D> {
+    pub fn new() -> Self {
+        Self(VecDeque::new())
+    }
+}
+
+impl<D> Default for BreadthFirst<D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Problem> Frontier<P> for BreadthFirst<P::Posibility> {
+    fn push(&mut self, _parent: &P, path: Vec<P::Posibility>) {
+        self.0.push_back(path)
+    }
+
+    fn pop(&mut self) -> Option<Vec<P::Posibility>> {
+        self.0.pop_front()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+/// Explore the node with the highest score first. Nodes with equal scores are explored in the
+/// order they have been discovered in.
+pub struct BestFirst<D, K, F> {
+    heap: BinaryHeap<Scored<D, K>>,
+    score: F,
+    /// Number of nodes pushed so far. Used to break ties between equal scores.
+    num_pushed: u64,
+}
+
+impl<D, K, F> BestFirst<D, K, F> {
+    /// `score` is invoked once for each node as it is pushed, with the state of its parent and its
+    /// path.
+    pub fn new(score: F) -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            score,
+            num_pushed: 0,
+        }
+    }
+}
+
+impl<P, K, F> Frontier<P> for BestFirst<P::Posibility, K, F>
+where
+    P: Problem,
+    K: Ord,
+    F: FnMut(&P, &[P::Posibility]) -> K,
+{
+    fn push(&mut self, parent: &P, path: Vec<P::Posibility>) {
+        let score = (self.score)(parent, &path);
+        self.heap.push(Scored {
+            score,
+            order: Reverse(self.num_pushed),
+            path,
+        });
+        self.num_pushed += 1;
+    }
+
+    fn pop(&mut self) -> Option<Vec<P::Posibility>> {
+        self.heap.pop().map(|scored| scored.path)
+    }
+
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+}
+
+/// Entry of [`BestFirst`]. Ordered by score first and order of insertion second.
+struct Scored<D, K> {
+    score: K,
+    order: Reverse<u64>,
+    path: Vec<D>,
+}
+
+impl<D, K: Ord> PartialEq for Scored<D, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<D, K: Ord> Eq for Scored<D, K> {}
+
+impl<D, K: Ord> PartialOrd for Scored<D, K> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<D, K: Ord> Ord for Scored<D, K> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.score, self.order).cmp(&(&other.score, other.order))
+    }
+}
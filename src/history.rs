@@ -0,0 +1,49 @@
+use alloc::vec::Vec;
+
+use crate::{Observer, Problem, Solutions};
+
+impl<P: Problem, O: Observer<P>> Solutions<P, O> {
+    /// Yields each solution together with the decisions leading to it. Use
+    /// [`Self::for_each_solution`] or [`Self::history`] to borrow the decisions instead of copying
+    /// them.
+    pub fn with_history(self) -> WithHistory<P, O> {
+        WithHistory(self)
+    }
+}
+
+/// Iterator yielding solutions together with the decisions leading to them. See
+/// [`Solutions::with_history`].
+pub struct WithHistory<P: Problem, O = ()>(Solutions<P, O>);
+
+impl<P: Problem, O> WithHistory<P, O> {
+    /// The underlying search, e.g. to access its [`Solutions::stats`].
+    pub fn solutions(&self) -> &Solutions<P, O> {
+        &self.0
+    }
+}
+
+impl<P: Problem, O: Observer<P>> Iterator for WithHistory<P, O> {
+    type Item = (P::Solution, Vec<P::Posibility>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let solution = self.0.next()?;
+        Some((solution, self.0.history.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_problems::Stairs, Solutions};
+
+    #[test]
+    fn yield_decisions_leading_to_solution() {
+        let solutions = Solutions::new(Stairs::new(3))
+            .with_history()
+            .collect::<Vec<_>>();
+
+        assert_eq!(3, solutions.len());
+        for (solution, history) in solutions {
+            assert_eq!(solution, history);
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use alloc::{collections::VecDeque, vec::Vec};
+
+use crate::{Candidate, Problem, Solutions};
+
+/// A subtree of the search tree, described by the decisions leading to its root. Created by
+/// [`Job::partition`]. Enable the `serde` feature in order to send jobs to other processes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Job<D> {
+    decisions: Vec<D>,
+}
+
+impl<D: Copy> Job<D> {
+    /// Splits the search tree of `init` into at least `min_jobs` disjoint subtrees, which together
+    /// contain each node below the root exactly once.
+    ///
+    /// The tree is expanded breadth first, so the jobs are of similar depth. Nodes without children
+    /// are dropped. Nodes which are solutions are never split, but become jobs on their own, since
+    /// their children are only searched if configured with [`Solutions::with_expanded_solutions`].
+    /// So fewer than `min_jobs` jobs are returned, if there are not enough nodes which could be
+    /// split. E.g. if every node is a solution, there is exactly one job for each child of the
+    /// root, no matter how large the tree is.
+    ///
+    /// `init` is changed using [`Problem::what_if`] in order to expand nodes and is restored using
+    /// [`Problem::undo`] afterwards.
+    pub fn partition<P>(init: &mut P, min_jobs: usize) -> Vec<Self>
+    where
+        P: Problem<Posibility = D>,
+    {
+        // Paths to the roots of subtrees which may still be split further.
+        let mut open = VecDeque::from([Vec::new()]);
+        let mut solutions = Vec::new();
+        let mut decisions = Vec::new();
+        while solutions.len() + open.len() < min_jobs {
+            let Some(path) = open.pop_front() else {
+                break;
+            };
+            for &decision in &path {
+                init.what_if(decision);
+            }
+            // Like `Solutions::new`, we do not check if the root is a solution.
+            let is_solution = !path.is_empty() && init.is_solved(&path);
+            if !is_solution {
+                decisions.clear();
+                init.extend_possibilities(&mut decisions, &path);
+                open.extend(decisions.iter().map(|&decision| {
+                    let mut child = path.clone();
+                    child.push(decision);
+                    child
+                }));
+            }
+            for (index, last) in path.iter().enumerate().rev() {
+                init.undo(last, &path[..index]);
+            }
+            if is_solution {
+                solutions.push(path);
+            }
+        }
+        solutions
+            .into_iter()
+            .chain(open)
+            .map(|decisions| Job { decisions })
+            .collect()
+    }
+
+    /// Decisions leading from the initial state to the root of the subtree.
+    pub fn decisions(&self) -> &[D] {
+        &self.decisions
+    }
+
+    /// Searches the subtree, including its root. `init` must be in the same state as the problem
+    /// passed to [`Job::partition`].
+    pub fn solutions<P>(&self, init: P) -> Solutions<P>
+    where
+        P: Problem<Posibility = D>,
+    {
+        Solutions::from_job(init, &self.decisions)
+    }
+}
+
+impl<P: Problem> Solutions<P> {
+    /// A search restricted to the subtree, whose root is reached by the decisions in `job`. Unlike
+    /// [`Self::from_history`], the root is checked for being a solution, unless it is the root of
+    /// the entire search tree.
+    pub(crate) fn from_job(mut init: P, job: &[P::Posibility]) -> Self {
+        let Some((&root, prefix)) = job.split_last() else {
+            return Self::new(init);
+        };
+        for &decision in prefix {
+            init.what_if(decision);
+        }
+        let mut solutions = Self::new_empty(init);
+        solutions.history.extend_from_slice(prefix);
+        solutions.open.push(Candidate {
+            count: job.len(),
+            possibility: root,
+        });
+        solutions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        test_problems::{Queens, Stairs, Subsets},
+        Solutions,
+    };
+
+    use super::Job;
+
+    #[test]
+    fn jobs_cover_search_tree_exactly_once() {
+        let mut expected = Solutions::new(Queens::new(6)).collect::<Vec<_>>();
+        expected.sort();
+
+        let mut queens = Queens::new(6);
+        let jobs = Job::partition(&mut queens, 10);
+        let mut actual = jobs
+            .iter()
+            .flat_map(|job| job.solutions(queens.clone()))
+            .collect::<Vec<_>>();
+        actual.sort();
+
+        assert!(jobs.len() >= 10);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn solutions_become_jobs_of_their_own() {
+        let mut stairs = Stairs::new(3);
+        let jobs = Job::partition(&mut stairs, 100);
+
+        // Fewer jobs than requested, since each is a solution.
+        let mut decisions = jobs.iter().map(Job::decisions).collect::<Vec<_>>();
+        decisions.sort();
+        assert_eq!(vec![&[1, 1, 1][..], &[1, 2], &[2, 1]], decisions);
+        // State has been restored
+        assert_eq!(Stairs::new(3), stairs);
+    }
+
+    #[test]
+    fn never_split_solutions() {
+        // 31 nodes, each of them a solution
+        let jobs = Job::partition(&mut Subsets::new(5), 7);
+
+        let roots = jobs.iter().map(Job::decisions).collect::<Vec<_>>();
+        assert_eq!(vec![&[0][..], &[1], &[2], &[3], &[4]], roots);
+    }
+
+    #[test]
+    fn expand_solution_jobs() {
+        let mut subsets = Subsets::new(4);
+        let num_solutions = Job::partition(&mut subsets, 5)
+            .iter()
+            .map(|job| {
+                job.solutions(subsets.clone())
+                    .with_expanded_solutions()
+                    .count()
+            })
+            .sum::<usize>();
+
+        assert_eq!(15, num_solutions);
+    }
+
+    #[test]
+    fn single_job_is_entire_search() {
+        let jobs = Job::partition(&mut Queens::new(4), 1);
+
+        assert_eq!(1, jobs.len());
+        assert_eq!(2, jobs[0].solutions(Queens::new(4)).count());
+    }
+}
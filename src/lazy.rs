@@ -0,0 +1,187 @@
+use alloc::{vec, vec::Vec};
+use core::iter::Peekable;
+
+use crate::Stats;
+
+/// Like [`crate::Problem`], but the children of a node are produced one at a time by an iterator.
+/// Used by the [`LazySolutions`] iterator. Worth it for problems with many possibilities per node,
+/// since only the possibilities actually visited are ever generated.
+pub trait LazyProblem {
+    /// See [`crate::Problem::Posibility`].
+    type Posibility: Copy;
+    /// See [`crate::Problem::Solution`].
+    type Solution;
+    /// Produces the decisions to be considered next. It is stored while the subtrees of its
+    /// possibilities are searched, so it must not borrow the problem, whose state changes in the
+    /// meantime. Instead it needs to own whatever it needs, e.g. a range of values.
+    type Children: Iterator<Item = Self::Posibility>;
+
+    /// Decisions to be considered next. See [`crate::Problem::extend_possibilities`].
+    fn children(&self, history: &[Self::Posibility]) -> Self::Children;
+
+    /// See [`crate::Problem::undo`].
+    fn undo(&mut self, last: &Self::Posibility, history: &[Self::Posibility]);
+
+    /// See [`crate::Problem::what_if`].
+    fn what_if(&mut self, decision: Self::Posibility);
+
+    /// See [`crate::Problem::is_solution`].
+    fn is_solution(&self, history: &[Self::Posibility]) -> Option<Self::Solution>;
+}
+
+/// An iterator performing backtracking to find solutions to a [`LazyProblem`]. Works like
+/// [`crate::Solutions`] with its default [`crate::VisitOrder`], but instead of an open list it
+/// keeps one iterator over the unvisited children for each node on the current path. Therefore
+/// [`Stats::max_open`] stays zero.
+pub struct LazySolutions<P: LazyProblem> {
+    /// Remaining children of each node on the path to the current one. The current node has no
+    /// entry, if it is a solution or if all of its children have been visited.
+    cursors: Vec<Peekable<P::Children>>,
+    history: Vec<P::Posibility>,
+    current: P,
+    stats: Stats,
+}
+
+impl<P: LazyProblem> LazySolutions<P> {
+    pub fn new(init: P) -> Self {
+        let root = init.children(&[]).peekable();
+        Self {
+            cursors: vec![root],
+            history: Vec::new(),
+            current: init,
+            stats: Stats::default(),
+        }
+    }
+
+    /// Counters describing the work done by the search so far.
+    pub fn stats(&self) -> &Stats {
+        &self.stats
+    }
+
+    /// Decisions leading from the initial state to the current one.
+    pub fn history(&self) -> &[P::Posibility] {
+        &self.history
+    }
+
+    /// The problem in its current state.
+    pub fn problem(&self) -> &P {
+        &self.current
+    }
+}
+
+impl<P: LazyProblem> Iterator for LazySolutions<P> {
+    type Item = P::Solution;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            // Current node has no children left to visit
+            if self.cursors.len() == self.history.len() {
+                let last = self.history.pop()?;
+                self.current.undo(&last, &self.history);
+                self.stats.backtracks += 1;
+                continue;
+            }
+            let Some(decision) = self.cursors.last_mut().unwrap().next() else {
+                self.cursors.pop();
+                continue;
+            };
+
+            self.current.what_if(decision);
+            self.history.push(decision);
+            self.stats.nodes += 1;
+            self.stats.max_depth = self.stats.max_depth.max(self.history.len());
+
+            if let Some(solution) = self.current.is_solution(&self.history) {
+                self.stats.solutions += 1;
+                return Some(solution);
+            }
+
+            let mut children = self.current.children(&self.history).peekable();
+            if children.peek().is_none() {
+                self.stats.dead_ends += 1;
+            } else {
+                self.cursors.push(children);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::Range;
+
+    use crate::{test_problems::Queens, Problem, Solutions};
+
+    use super::{LazyProblem, LazySolutions};
+
+    impl LazyProblem for Queens {
+        type Posibility = u32;
+        type Solution = Vec<u32>;
+        type Children = std::vec::IntoIter<u32>;
+
+        fn children(&self, history: &[u32]) -> Self::Children {
+            let mut possibilities = Vec::new();
+            self.extend_possibilities(&mut possibilities, history);
+            possibilities.into_iter()
+        }
+
+        fn undo(&mut self, last: &u32, history: &[u32]) {
+            Problem::undo(self, last, history)
+        }
+
+        fn what_if(&mut self, decision: u32) {
+            Problem::what_if(self, decision)
+        }
+
+        fn is_solution(&self, history: &[u32]) -> Option<Vec<u32>> {
+            Problem::is_solution(self, history)
+        }
+    }
+
+    /// Choose three numbers of the entire range of `u32`.
+    struct Triple;
+
+    impl LazyProblem for Triple {
+        type Posibility = u32;
+        type Solution = Vec<u32>;
+        type Children = Range<u32>;
+
+        fn children(&self, _history: &[u32]) -> Range<u32> {
+            0..u32::MAX
+        }
+
+        fn undo(&mut self, _last: &u32, _history: &[u32]) {}
+
+        fn what_if(&mut self, _decision: u32) {}
+
+        fn is_solution(&self, history: &[u32]) -> Option<Vec<u32>> {
+            (history.len() == 3).then(|| history.to_vec())
+        }
+    }
+
+    #[test]
+    fn same_solutions_and_stats_as_eager_search() {
+        let mut eager = Solutions::new(Queens::new(6));
+        let mut lazy = LazySolutions::new(Queens::new(6));
+
+        assert_eq!(
+            eager.by_ref().collect::<Vec<_>>(),
+            lazy.by_ref().collect::<Vec<_>>()
+        );
+        let eager_stats = eager.stats();
+        let lazy_stats = lazy.stats();
+        assert_eq!(eager_stats.nodes, lazy_stats.nodes);
+        assert_eq!(eager_stats.dead_ends, lazy_stats.dead_ends);
+        assert_eq!(eager_stats.solutions, lazy_stats.solutions);
+        assert_eq!(None, lazy.next());
+    }
+
+    #[test]
+    fn only_generate_visited_children() {
+        let mut solutions = LazySolutions::new(Triple);
+
+        assert_eq!(Some(vec![0, 0, 0]), solutions.next());
+        assert_eq!(Some(vec![0, 0, 1]), solutions.next());
+        assert_eq!(4, solutions.stats().nodes);
+    }
+}
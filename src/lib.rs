@@ -1,4 +1,71 @@
 //! Find solutions with backtracking.
+//!
+//! The crate works without the standard library, as long as an allocator is available. Disable the
+//! default `std` feature to do so. Deadlines, the parallel search, the transposition table and
+//! tracing require `std`. Cancellation flags require a target supporting atomic operations on
+//! pointers.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+pub use self::{
+    branch_and_bound::{BranchAndBound, Optimize},
+    budget::{Interruption, SearchResult},
+    checked::Checked,
+    checkpoint::Checkpoint,
+    deepening::IterativeDeepening,
+    explore::Explore,
+    fallible::{TryProblem, TrySolutions},
+    frontier::{BestFirst, BreadthFirst, DepthFirst, Frontier},
+    history::WithHistory,
+    job::Job,
+    lazy::{LazyProblem, LazySolutions},
+    observer::Observer,
+    order::VisitOrder,
+    snapshot::{SnapshotPolicy, Snapshots},
+    step::Step,
+    symmetry::{Canonical, Symmetric, WithOrbitSize},
+};
+
+#[cfg(feature = "std")]
+pub use self::{parallel::ParallelSolutions, trace::Trace, transposition::StateKey};
+
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::{boxed::Box, vec::Vec};
+use core::ops::ControlFlow;
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::AtomicBool;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+use self::{budget::Budget, transposition::Transpositions};
+
+mod branch_and_bound;
+mod budget;
+mod checked;
+mod checkpoint;
+mod deepening;
+mod explore;
+mod fallible;
+mod frontier;
+mod history;
+mod job;
+mod lazy;
+mod observer;
+mod order;
+#[cfg(feature = "std")]
+mod parallel;
+mod snapshot;
+mod step;
+mod symmetry;
+#[cfg(test)]
+mod test_problems;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+#[cfg(feature = "std")]
+mod trace;
+mod transposition;
 
 /// A problem to be tackled with backtracking. Used by the [`Solutions`] iterator which can find
 /// solutions for ypes implementing [`Problem`].
@@ -18,7 +85,8 @@ pub trait Problem {
     type Solution;
 
     /// Extends `possibilities` with a set of decisions to be considered next. Implementations may
-    /// assume that the `possibilities` is empty if invoked through the `Solutions` iterator.
+    /// assume that the `possibilities` is empty if invoked through the `Solutions` iterator. By
+    /// default `Solutions` visits them in the order they are produced, see [`VisitOrder`].
     fn extend_possibilities(
         &self,
         possibilities: &mut Vec<Self::Posibility>,
@@ -36,82 +104,593 @@ pub trait Problem {
     /// Check if the candidate state we are looking at is a solution to our probelm. If so extract
     /// the information we are interessted in.
     fn is_solution(&self, history: &[Self::Posibility]) -> Option<Self::Solution>;
+
+    /// `true` if the current state is a solution. Used instead of [`Problem::is_solution`] by
+    /// [`Solutions::for_each_solution`], which does not need to extract the solution. The default
+    /// implementation calls [`Problem::is_solution`], so it is worth overriding if extracting the
+    /// solution is expensive.
+    fn is_solved(&self, history: &[Self::Posibility]) -> bool {
+        self.is_solution(history).is_some()
+    }
 }
 
 /// An iterator performing backtracking to find solutions to a problem.
-pub struct Solutions<P: Problem> {
+///
+/// The search tree is explored depth first. Since each candidate is a child of the current node or
+/// of one of its ancestors, the state is rolled back using [`Problem::undo`] only. Use [`Explore`]
+/// to explore the search tree in a different order. The children of each node are visited in the
+/// order they have been produced by [`Problem::extend_possibilities`], unless configured otherwise
+/// with [`Solutions::with_visit_order`].
+pub struct Solutions<P: Problem, O = ()> {
     decisions: Vec<P::Posibility>,
     open: Vec<Candidate<P::Posibility>>,
     /// Keeps track of the decisions, which yielded the current problem state, starting from the
     /// initial state.
     history: Vec<P::Posibility>,
     current: P,
+    stats: Stats,
+    /// Candidates deeper than this are never put into the open list.
+    max_depth: usize,
+    /// `true` if at least one candidate has been discarded, because it would have been deeper than
+    /// `max_depth`.
+    cut_off: bool,
+    /// Nodes in `history` up to this depth have had part of their subtree cut off by `max_depth`.
+    /// Their states must not be remembered as fully explored.
+    cut_off_depth: usize,
+    /// `true` if the children of solutions are explored, too.
+    expand_solutions: bool,
+    /// Order in which the children of a node are visited.
+    order: VisitOrder<P>,
+    /// Conditions under which the search is interrupted before finding the next solution.
+    budget: Budget,
+    /// States which have already been fully explored. Only used if configured with
+    /// [`Self::with_transposition_table`].
+    transpositions: Option<Box<dyn Transpositions<P> + Send>>,
+    /// Notified about each step of the search.
+    observer: O,
 }
 
 impl<G: Problem> Solutions<G> {
     pub fn new(init: G) -> Self {
-        let mut possible_moves = Vec::new();
-        init.extend_possibilities(&mut possible_moves, &[]);
-        let open = possible_moves
-            .iter()
-            .map(|pos| Candidate {
-                count: 1,
-                possibility: *pos,
-            })
-            .collect();
+        Self::from_history(init, &[])
+    }
+
+    /// Searches only the subtree below the node reached by the decisions in `prefix`, which are
+    /// replayed using [`Problem::what_if`]. The search never backtracks above that node, and the
+    /// node itself is not checked for being a solution. Depths, e.g. in [`Self::with_max_depth`],
+    /// still count the decisions in `prefix`.
+    pub fn from_history(mut init: G, prefix: &[G::Posibility]) -> Self {
+        for &decision in prefix {
+            init.what_if(decision);
+        }
+        let mut solutions = Self::new_empty(init);
+        solutions.history.extend_from_slice(prefix);
+        solutions.extend_search_tree();
+        solutions
+    }
+
+    /// A search without any candidates in its open list.
+    fn new_empty(init: G) -> Self {
         Self {
-            decisions: possible_moves,
-            open,
+            decisions: Vec::new(),
+            open: Vec::new(),
             history: Vec::new(),
             current: init,
+            stats: Stats::default(),
+            max_depth: usize::MAX,
+            cut_off: false,
+            cut_off_depth: 0,
+            expand_solutions: false,
+            order: VisitOrder::AsProduced,
+            budget: Budget::default(),
+            transpositions: None,
+            observer: (),
         }
     }
 }
 
-impl<G: Problem> Iterator for Solutions<G> {
-    type Item = G::Solution;
+impl<G: Problem, O: Observer<G>> Solutions<G, O> {
+    /// Limits the search to histories with at most `max_depth` decisions. Candidates deeper than
+    /// that are never considered.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        let len_before = self.open.len();
+        let mut cut_off_depth = self.cut_off_depth;
+        self.open.retain(|candidate| {
+            let keep = candidate.count <= max_depth;
+            if !keep {
+                cut_off_depth = cut_off_depth.max(candidate.count - 1);
+            }
+            keep
+        });
+        self.cut_off_depth = cut_off_depth;
+        self.cut_off |= self.open.len() != len_before;
+        self
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        while let Some(Candidate {
+    /// `true` if the depth limit set with [`Self::with_max_depth`] caused at least one candidate to
+    /// be discarded. If this is `false` after the iterator is exhausted, the search did explore the
+    /// entire tree.
+    pub fn is_cut_off(&self) -> bool {
+        self.cut_off
+    }
+
+    /// Keeps searching below solutions, for problems whose solutions may have other solutions as
+    /// descendants, e.g. enumerating all subsets with some property. By default the children of a
+    /// solution are never explored.
+    ///
+    /// Solutions are still yielded in depth first pre-order: each solution comes before all of its
+    /// descendants, and all of those come before any solution in the subtree of a later sibling.
+    pub fn with_expanded_solutions(mut self) -> Self {
+        self.expand_solutions = true;
+        self
+    }
+
+    /// Takes back all decisions made so far using [`Problem::undo`] and returns the problem in the
+    /// state it has been passed to the constructor in.
+    pub fn into_inner(mut self) -> G {
+        while let Some(last) = self.history.pop() {
+            self.current.undo(&last, &self.history);
+        }
+        self.current
+    }
+
+    /// Starts a new search for `init`, like [`Self::new`] would, but keeps the configuration and
+    /// the observer. Reuses the memory allocated by the previous search. Statistics and the
+    /// transposition table are cleared.
+    pub fn reset(&mut self, init: G) {
+        self.decisions.clear();
+        self.open.clear();
+        self.history.clear();
+        self.current = init;
+        self.stats = Stats::default();
+        self.cut_off = false;
+        self.cut_off_depth = 0;
+        if let Some(transpositions) = &mut self.transpositions {
+            transpositions.clear();
+        }
+        self.extend_search_tree();
+    }
+
+    /// Counters describing the work done by the search so far. May be inspected at any time during
+    /// or after the iteration.
+    pub fn stats(&self) -> &Stats {
+        &self.stats
+    }
+
+    /// Notifies `observer` about each step of the search from now on. The observer is told about
+    /// the candidates which already are in the open list right away.
+    pub fn with_observer<O2: Observer<G>>(self, mut observer: O2) -> Solutions<G, O2> {
+        for candidate in &self.open {
+            observer.push(&candidate.possibility, &self.history[..candidate.count - 1]);
+        }
+        Solutions {
+            decisions: self.decisions,
+            open: self.open,
+            history: self.history,
+            current: self.current,
+            stats: self.stats,
+            max_depth: self.max_depth,
+            cut_off: self.cut_off,
+            cut_off_depth: self.cut_off_depth,
+            expand_solutions: self.expand_solutions,
+            order: self.order,
+            budget: self.budget,
+            transpositions: self.transpositions,
+            observer,
+        }
+    }
+
+    pub fn observer(&self) -> &O {
+        &self.observer
+    }
+
+    pub fn observer_mut(&mut self) -> &mut O {
+        &mut self.observer
+    }
+
+    /// Calls `f` with the problem and the history for each solution, rather than extracting the
+    /// solution using [`Problem::is_solution`]. Solutions are detected using [`Problem::is_solved`]
+    /// instead. This avoids e.g. cloning the problem state for each solution, if you are only
+    /// interested in parts of it. The search stops early if `f` returns [`ControlFlow::Break`].
+    ///
+    /// Deadlines, node budgets and cancellation flags are not checked.
+    pub fn for_each_solution<B>(
+        &mut self,
+        mut f: impl FnMut(&G, &[G::Posibility]) -> ControlFlow<B>,
+    ) -> ControlFlow<B> {
+        while let Some(candidate) = self.open.pop() {
+            self.enter(candidate);
+            if let Step::Solution(()) =
+                self.evaluate_by(|problem, history| problem.is_solved(history).then_some(()))
+            {
+                f(&self.current, &self.history)?;
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Interrupt the search once `deadline` has passed.
+    #[cfg(feature = "std")]
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.budget.deadline = Some(deadline);
+        self
+    }
+
+    /// Interrupt the search once it entered `max_nodes` nodes in total. See [`Stats::nodes`].
+    pub fn with_node_budget(mut self, max_nodes: u64) -> Self {
+        self.budget.max_nodes = Some(max_nodes);
+        self
+    }
+
+    /// Interrupt the search once `cancel` is set to `true`, e.g. from another thread. Only
+    /// available on targets supporting atomic operations on pointers.
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn with_cancel_flag(mut self, cancel: Arc<AtomicBool>) -> Self {
+        self.budget.cancel = Some(cancel);
+        self
+    }
+
+    /// Searches for the next solution, like [`Iterator::next`], but tells apart an exhausted search
+    /// from one interrupted by a deadline, node budget or cancellation flag. An interrupted search
+    /// is left in a consistent state, so it is fine to call this method again, e.g. after setting
+    /// the cancellation flag back to `false`.
+    pub fn next_solution(&mut self) -> SearchResult<G::Solution> {
+        while !self.open.is_empty() {
+            if let Some(interruption) = self.budget.check(&self.stats) {
+                return SearchResult::Interrupted(interruption);
+            }
+            let candidate = self.open.pop().unwrap();
+            if let Some(solution) = self.visit(candidate) {
+                return SearchResult::Solution(solution);
+            }
+        }
+        SearchResult::Exhausted
+    }
+
+    /// Enters the node described by `candidate`. Returns the solution if the node is one, otherwise
+    /// puts its children into the open list.
+    fn visit(&mut self, candidate: Candidate<G::Posibility>) -> Option<G::Solution> {
+        self.enter(candidate);
+        match self.evaluate() {
+            Step::Solution(solution) => Some(solution),
+            _ => None,
+        }
+    }
+
+    /// Changes the current state to the node described by `candidate`.
+    fn enter(&mut self, candidate: Candidate<G::Posibility>) {
+        let Candidate {
             count,
             possibility: mov,
-        }) = self.open.pop()
-        {
-            // Unroll all the moves until our current state is identical with the one which we
-            // had once we put that mov into the open list. We want to be one move behind so
-            // we need to play the move in order to get the desired state
-            for _ in 0..self.history.len() as i32 - count + 1 {
-                let last = self.history.pop().unwrap();
-                self.current.undo(&last, &self.history);
+        } = candidate;
+
+        // Unroll all the moves until our current state is identical with the one which we had once
+        // we put that mov into the open list. We want to be one move behind so we need to play the
+        // move in order to get the desired state
+        while self.history.len() >= count {
+            self.backtrack();
+        }
+
+        // We advance one move deeper into the search tree
+        self.current.what_if(mov);
+        self.history.push(mov);
+        self.stats.nodes += 1;
+        self.stats.max_depth = self.stats.max_depth.max(self.history.len());
+        self.observer.enter(&self.current, &self.history);
+    }
+
+    /// Takes back the last decision and returns it.
+    fn backtrack(&mut self) -> G::Posibility {
+        // We are done with the subtree of the current state. Unless it has been cut off, there is
+        // nothing left to find below it.
+        let depth = self.history.len();
+        if self.cut_off_depth < depth {
+            if let Some(transpositions) = &mut self.transpositions {
+                transpositions.insert(&self.current);
             }
+        }
+        self.cut_off_depth = self.cut_off_depth.min(depth - 1);
+        let last = self.history.pop().unwrap();
+        self.current.undo(&last, &self.history);
+        self.stats.backtracks += 1;
+        self.observer.backtrack(&last, &self.history);
+        last
+    }
 
-            // We advance one move deeper into the search tree
-            self.current.what_if(mov);
-            self.history.push(mov);
+    /// Checks if the current node is a solution, otherwise puts its children into the open list.
+    /// The children of solutions are only put into the open list if configured with
+    /// [`Self::with_expanded_solutions`].
+    fn evaluate(&mut self) -> Step<G::Posibility, G::Solution> {
+        self.evaluate_by(G::is_solution)
+    }
+
+    /// Like [`Self::evaluate`], but uses `check` to tell if the current node is a solution.
+    fn evaluate_by<S>(
+        &mut self,
+        check: impl FnOnce(&G, &[G::Posibility]) -> Option<S>,
+    ) -> Step<G::Posibility, S> {
+        let count = self.history.len();
 
-            // Emit solution
-            if let Some(solution) = self.current.is_solution(&self.history) {
-                return Some(solution);
+        // Skip states we already have been in
+        if self
+            .transpositions
+            .as_ref()
+            .is_some_and(|transpositions| transpositions.contains(&self.current))
+        {
+            self.stats.pruned += 1;
+            self.observer.pruned(&self.history);
+            return Step::Pruned;
+        }
+
+        // Emit solution
+        if let Some(solution) = check(&self.current, &self.history) {
+            self.stats.solutions += 1;
+            self.observer.solution(&self.current, &self.history);
+            if self.expand_solutions {
+                self.extend_search_tree();
             }
+            return Step::Solution(solution);
+        }
 
-            // Extend search tree
-            self.decisions.clear();
-            self.current
-                .extend_possibilities(&mut self.decisions, &self.history);
-            self.open
-                .extend(self.decisions.iter().map(|&position| Candidate {
-                    count: count + 1,
-                    possibility: position,
-                }))
+        if !self.extend_search_tree() {
+            self.stats.pruned += 1;
+            self.observer.pruned(&self.history);
+            return Step::Pruned;
+        }
+        if self.decisions.is_empty() {
+            self.stats.dead_ends += 1;
+            self.observer.dead_end(&self.history);
+            return Step::DeadEnd;
         }
-        None
+        Step::Descend(self.history[count - 1])
     }
+
+    /// Puts the children of the current node into the open list, unless the depth limit forbids
+    /// it. `false` if there are children, which have been discarded. The children are left in
+    /// `decisions`.
+    fn extend_search_tree(&mut self) -> bool {
+        let count = self.history.len();
+        self.decisions.clear();
+        self.current
+            .extend_possibilities(&mut self.decisions, &self.history);
+        if self.decisions.is_empty() {
+            return true;
+        }
+        if count >= self.max_depth {
+            self.cut_off = true;
+            self.cut_off_depth = count;
+            return false;
+        }
+        self.order
+            .arrange(&self.current, &mut self.decisions, |&decision| decision);
+        for &decision in &self.decisions {
+            self.observer.push(&decision, &self.history);
+            self.open.push(Candidate {
+                count: count + 1,
+                possibility: decision,
+            });
+        }
+        self.stats.max_open = self.stats.max_open.max(self.open.len());
+        true
+    }
+}
+
+impl<G: Problem, O: Observer<G>> Iterator for Solutions<G, O> {
+    type Item = G::Solution;
+
+    /// Next solution. `None` if the search is exhausted or has been interrupted. Use
+    /// [`Solutions::next_solution`] to tell these two apart.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_solution() {
+            SearchResult::Solution(solution) => Some(solution),
+            SearchResult::Exhausted | SearchResult::Interrupted(_) => None,
+        }
+    }
+}
+
+/// Counters describing how much work a [`Solutions`] iterator had to do.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stats {
+    /// Number of nodes entered, i.e. calls to [`Problem::what_if`].
+    pub nodes: u64,
+    /// Number of decisions taken back, i.e. calls to [`Problem::undo`].
+    pub backtracks: u64,
+    /// Number of nodes which are no solution and for which [`Problem::extend_possibilities`] did
+    /// not add any possibility.
+    pub dead_ends: u64,
+    /// Number of nodes entered, whose children are not explored, even though the node is neither a
+    /// solution nor a dead end. E.g. because their state has been found in the transposition table,
+    /// their bound could not beat the best solution so far, or because of the depth limit.
+    pub pruned: u64,
+    /// Length of the longest history seen so far.
+    pub max_depth: usize,
+    /// Largest number of candidates which have been waiting to be explored at the same time.
+    pub max_open: usize,
+    /// Number of solutions yielded by the iterator.
+    pub solutions: u64,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Candidate<P> {
     /// Counts the number of turns made to get to this candidate. We keep track of this so we can
     /// call undo the appropriate number of types, if we roll back to an earlier state.
-    count: i32,
+    count: usize,
     /// Possibility which will lead to this candidate
     possibility: P,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        ops::ControlFlow,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+    };
+
+    use crate::{
+        test_problems::{Queens, Stairs, Subsets},
+        Interruption, SearchResult, Solutions, Stats, Step,
+    };
+
+    #[test]
+    fn recover_initial_problem() {
+        let mut solutions = Solutions::new(Stairs::new(5));
+        solutions.next().unwrap();
+
+        assert_eq!(Stairs::new(5), solutions.into_inner());
+    }
+
+    #[test]
+    fn reset_for_new_search() {
+        let mut solutions = Solutions::new(Stairs::new(6)).with_max_depth(4);
+        solutions.by_ref().for_each(drop);
+        let capacity = solutions.open.capacity();
+
+        solutions.reset(Stairs::new(5));
+        let actual = solutions.by_ref().collect::<Vec<_>>();
+
+        let mut fresh = Solutions::new(Stairs::new(5)).with_max_depth(4);
+        let expected = fresh.by_ref().collect::<Vec<_>>();
+        assert_eq!(expected, actual);
+        assert_eq!(fresh.stats(), solutions.stats());
+        assert_eq!(fresh.is_cut_off(), solutions.is_cut_off());
+        assert_eq!(capacity, solutions.open.capacity());
+    }
+
+    #[test]
+    fn search_subtree_below_history() {
+        let expected = Solutions::new(Queens::new(6))
+            .filter(|solution| solution[..2] == [1, 3])
+            .collect::<Vec<_>>();
+
+        let actual = Solutions::from_history(Queens::new(6), &[1, 3]).collect::<Vec<_>>();
+
+        assert!(!actual.is_empty());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn never_backtrack_above_history() {
+        let mut solutions = Solutions::from_history(Queens::new(6), &[1, 3]);
+        while solutions.step() != Step::Exhausted {
+            assert_eq!(&[1, 3], &solutions.history()[..2]);
+        }
+    }
+
+    #[test]
+    fn expand_solutions_in_pre_order() {
+        let solutions = Solutions::new(Subsets::new(3))
+            .with_expanded_solutions()
+            .collect::<Vec<_>>();
+
+        let expected: [&[u32]; 7] = [&[0], &[0, 1], &[0, 1, 2], &[0, 2], &[1], &[1, 2], &[2]];
+        assert_eq!(&expected[..], solutions);
+    }
+
+    #[test]
+    fn do_not_expand_solutions_by_default() {
+        let solutions = Solutions::new(Subsets::new(3)).collect::<Vec<_>>();
+
+        assert_eq!(vec![vec![0], vec![1], vec![2]], solutions);
+    }
+
+    #[test]
+    fn solutions_are_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Solutions<Queens>>();
+    }
+
+    #[test]
+    fn max_depth_prevents_finding_deeper_solutions() {
+        let mut solutions = Solutions::new(Queens::new(4)).with_max_depth(3);
+
+        assert_eq!(None, solutions.next());
+        assert_eq!(3, solutions.stats().max_depth);
+        assert!(solutions.is_cut_off());
+    }
+
+    #[test]
+    fn exhaustive_search_is_not_cut_off() {
+        let mut solutions = Solutions::new(Queens::new(4)).with_max_depth(4);
+
+        assert_eq!(2, solutions.by_ref().count());
+        assert!(!solutions.is_cut_off());
+    }
+
+    #[test]
+    fn node_budget_interrupts_search() {
+        let mut solutions = Solutions::new(Queens::new(8)).with_node_budget(10);
+
+        assert_eq!(
+            SearchResult::Interrupted(Interruption::NodeBudget),
+            solutions.next_solution()
+        );
+        assert_eq!(10, solutions.stats().nodes);
+    }
+
+    #[test]
+    fn resume_after_cancellation() {
+        let cancel = Arc::new(AtomicBool::new(true));
+        let mut solutions = Solutions::new(Queens::new(4)).with_cancel_flag(cancel.clone());
+
+        assert_eq!(
+            SearchResult::Interrupted(Interruption::Cancelled),
+            solutions.next_solution()
+        );
+        cancel.store(false, Ordering::Relaxed);
+
+        assert_eq!(2, solutions.by_ref().count());
+        assert_eq!(SearchResult::Exhausted, solutions.next_solution());
+    }
+
+    #[test]
+    fn stats_after_exhausting_four_queens() {
+        let mut solutions = Solutions::new(Queens::new(4));
+
+        let num_solutions = solutions.by_ref().count();
+
+        let expected = Stats {
+            nodes: 16,
+            backtracks: 14,
+            dead_ends: 4,
+            pruned: 0,
+            max_depth: 4,
+            max_open: 5,
+            solutions: 2,
+        };
+        assert_eq!(2, num_solutions);
+        assert_eq!(&expected, solutions.stats());
+    }
+
+    #[test]
+    fn borrow_each_solution() {
+        let mut solutions = Solutions::new(Queens::new(6));
+        let mut count = 0;
+
+        let flow = solutions.for_each_solution(|_queens, _history| {
+            count += 1;
+            ControlFlow::<()>::Continue(())
+        });
+
+        assert_eq!(ControlFlow::Continue(()), flow);
+        assert_eq!(4, count);
+        assert_eq!(4, solutions.stats().solutions);
+    }
+
+    #[test]
+    fn stop_borrowing_solutions_early() {
+        let mut solutions = Solutions::new(Queens::new(6));
+        let expected = Solutions::new(Queens::new(6)).next().unwrap();
+
+        let flow =
+            solutions.for_each_solution(|_queens, history| ControlFlow::Break(history.to_vec()));
+
+        assert_eq!(ControlFlow::Break(expected), flow);
+        assert_eq!(1, solutions.stats().solutions);
+    }
+}
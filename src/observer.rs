@@ -0,0 +1,113 @@
+use crate::Problem;
+
+/// Notified by [`crate::Solutions`] about each step of the search. All methods default to doing
+/// nothing, so implementations only need to override the events they are interested in. The
+/// depth of the current node is the length of `history`.
+///
+/// `()` is the observer used by default. Since all its methods are empty, they are optimized away.
+pub trait Observer<P: Problem> {
+    /// Called after entering a node, i.e. after [`Problem::what_if`]. `history` includes the
+    /// decision leading to the node.
+    fn enter(&mut self, problem: &P, history: &[P::Posibility]) {
+        let _ = (problem, history);
+    }
+
+    /// Called after each call to [`Problem::undo`]. `history` no longer contains `last`.
+    fn backtrack(&mut self, last: &P::Posibility, history: &[P::Posibility]) {
+        let _ = (last, history);
+    }
+
+    /// Called if the current node is no solution and has no children.
+    fn dead_end(&mut self, history: &[P::Posibility]) {
+        let _ = history;
+    }
+
+    /// Called if the children of the current node are not explored, because its state has been
+    /// found in the transposition table or because of the depth limit.
+    fn pruned(&mut self, history: &[P::Posibility]) {
+        let _ = history;
+    }
+
+    /// Called for each candidate put into the open list. `history` leads to the parent of the
+    /// candidate.
+    fn push(&mut self, possibility: &P::Posibility, history: &[P::Posibility]) {
+        let _ = (possibility, history);
+    }
+
+    /// Called if the current node is a solution, before it is yielded.
+    fn solution(&mut self, problem: &P, history: &[P::Posibility]) {
+        let _ = (problem, history);
+    }
+}
+
+impl<P: Problem> Observer<P> for () {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_problems::Queens, Problem, Solutions};
+
+    use super::Observer;
+
+    /// Counts the events it is notified about.
+    #[derive(Default)]
+    struct Counter {
+        enter: u64,
+        backtrack: u64,
+        dead_end: u64,
+        pruned: u64,
+        push: u64,
+        solution: u64,
+        deepest: usize,
+    }
+
+    impl<P: Problem> Observer<P> for Counter {
+        fn enter(&mut self, _problem: &P, history: &[P::Posibility]) {
+            self.enter += 1;
+            self.deepest = self.deepest.max(history.len());
+        }
+
+        fn backtrack(&mut self, _last: &P::Posibility, _history: &[P::Posibility]) {
+            self.backtrack += 1;
+        }
+
+        fn dead_end(&mut self, _history: &[P::Posibility]) {
+            self.dead_end += 1;
+        }
+
+        fn pruned(&mut self, _history: &[P::Posibility]) {
+            self.pruned += 1;
+        }
+
+        fn push(&mut self, _possibility: &P::Posibility, _history: &[P::Posibility]) {
+            self.push += 1;
+        }
+
+        fn solution(&mut self, _problem: &P, _history: &[P::Posibility]) {
+            self.solution += 1;
+        }
+    }
+
+    #[test]
+    fn observed_events_match_stats() {
+        // Without and with nodes cut off by the depth limit
+        for max_depth in [6, 4] {
+            let mut solutions = Solutions::new(Queens::new(6))
+                .with_max_depth(max_depth)
+                .with_observer(Counter::default());
+
+            solutions.by_ref().count();
+
+            let stats = *solutions.stats();
+            let counter = solutions.observer();
+            assert_eq!(stats.nodes, counter.enter);
+            assert_eq!(stats.backtracks, counter.backtrack);
+            assert_eq!(stats.dead_ends, counter.dead_end);
+            assert_eq!(stats.pruned, counter.pruned);
+            assert_eq!(stats.solutions, counter.solution);
+            assert_eq!(stats.max_depth, counter.deepest);
+            // Each node entered has been pushed before, including the children of the root.
+            assert_eq!(counter.enter, counter.push);
+            assert_eq!(max_depth < 6, stats.pruned > 0);
+        }
+    }
+}
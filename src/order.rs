@@ -0,0 +1,120 @@
+use alloc::boxed::Box;
+use core::cmp::Ordering;
+
+use crate::{Observer, Problem, Solutions};
+
+/// Order in which [`Solutions`] visits the children of a node, i.e. the possibilities produced by
+/// [`Problem::extend_possibilities`]. Set with [`Solutions::with_visit_order`].
+pub enum VisitOrder<P: Problem> {
+    /// Visit the possibilities in the order they have been produced. This is the default, so
+    /// [`Problem::extend_possibilities`] may put the most promising possibilities first.
+    AsProduced,
+    /// Visit the possibilities in the reverse order they have been produced.
+    Reversed,
+    /// Visit the possibilities in ascending order, as determined by comparing them in the state of
+    /// their parent node. The sort is stable, so equal possibilities are visited in the order they
+    /// have been produced.
+    SortedBy(Box<Compare<P>>),
+}
+
+/// Compares two possibilities in the state of their parent.
+type Compare<P> =
+    dyn FnMut(&P, &<P as Problem>::Posibility, &<P as Problem>::Posibility) -> Ordering + Send;
+
+impl<P: Problem> VisitOrder<P> {
+    /// Visit the possibilities with the smallest key first. The key is computed in the state of the
+    /// parent node.
+    pub fn by_key<K: Ord>(mut key: impl FnMut(&P, &P::Posibility) -> K + Send + 'static) -> Self {
+        VisitOrder::SortedBy(Box::new(move |problem, a, b| {
+            key(problem, a).cmp(&key(problem, b))
+        }))
+    }
+
+    /// Rearranges the children of `parent`, given in the order they have been produced, so
+    /// popping them from the end yields them in visiting order.
+    pub(crate) fn arrange<T>(
+        &mut self,
+        parent: &P,
+        children: &mut [T],
+        possibility: impl Fn(&T) -> P::Posibility,
+    ) {
+        match self {
+            VisitOrder::AsProduced => children.reverse(),
+            VisitOrder::Reversed => (),
+            VisitOrder::SortedBy(compare) => {
+                children.sort_by(|a, b| compare(parent, &possibility(a), &possibility(b)));
+                children.reverse();
+            }
+        }
+    }
+}
+
+impl<P: Problem, O: Observer<P>> Solutions<P, O> {
+    /// Changes the order in which the children of each node are visited. Applies to the children
+    /// of nodes entered from now on, as well as to the unvisited children of the current node, e.g.
+    /// those of the initial state. The latter are rearranged as if they had been produced in the
+    /// order they would have been visited so far.
+    pub fn with_visit_order(mut self, mut order: VisitOrder<P>) -> Self {
+        let depth = self.history.len();
+        let num_children = self
+            .open
+            .iter()
+            .rev()
+            .take_while(|candidate| candidate.count == depth + 1)
+            .count();
+        let start = self.open.len() - num_children;
+        let children = &mut self.open[start..];
+        // Visiting order so far
+        children.reverse();
+        order.arrange(&self.current, children, |candidate| candidate.possibility);
+        self.resume_with_visit_order(order)
+    }
+
+    /// Like [`Self::with_visit_order`], but leaves the candidates already in the open list as they
+    /// are. Use this to restore the order of a search resumed with [`Solutions::from_checkpoint`].
+    pub fn resume_with_visit_order(mut self, order: VisitOrder<P>) -> Self {
+        self.order = order;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        test_problems::{Stairs, Subsets},
+        Solutions,
+    };
+
+    use super::VisitOrder;
+
+    #[test]
+    fn visit_as_produced_by_default() {
+        let solutions = Solutions::new(Stairs::new(3)).collect::<Vec<_>>();
+
+        assert_eq!(vec![vec![1, 1, 1], vec![1, 2], vec![2, 1]], solutions);
+    }
+
+    #[test]
+    fn visit_in_reverse() {
+        let solutions = Solutions::new(Subsets::new(3))
+            .with_visit_order(VisitOrder::Reversed)
+            .with_expanded_solutions()
+            .collect::<Vec<_>>();
+
+        let expected: [&[u32]; 7] = [&[2], &[1], &[1, 2], &[0], &[0, 2], &[0, 1], &[0, 1, 2]];
+        assert_eq!(&expected[..], solutions);
+    }
+
+    #[test]
+    fn visit_sorted_by_key() {
+        // Odd elements first, ties in the order produced
+        let solutions = Solutions::new(Subsets::new(4))
+            .with_visit_order(VisitOrder::by_key(|_, &element| element % 2 == 0))
+            .with_expanded_solutions()
+            .take(6)
+            .collect::<Vec<_>>();
+
+        let expected: [&[u32]; 6] = [&[1], &[1, 3], &[1, 2], &[1, 2, 3], &[3], &[0]];
+        assert_eq!(&expected[..], solutions);
+    }
+}
@@ -0,0 +1,251 @@
+use std::{
+    num::NonZeroUsize,
+    panic,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc::{channel, Receiver, Sender},
+        Arc, Condvar, Mutex,
+    },
+    thread::{self, available_parallelism, JoinHandle},
+};
+
+use crate::{Candidate, Problem, Solutions};
+
+/// Iterator over the solutions of a problem, which are searched for by several threads in parallel.
+///
+/// Each worker thread owns its own clone of the problem and performs the usual depth first search
+/// on a subtree. The subtrees are handed out as jobs, each described by the decisions leading to
+/// its root. Initially each possibility of the root is a job. Workers running out of work wait for
+/// a busy worker to split off the shallowest candidate of its open list, which is likely to be the
+/// largest subtree it could give away.
+///
+/// Solutions are yielded in the order they are found, which is not deterministic. Dropping the
+/// iterator stops the search.
+pub struct ParallelSolutions<P: Problem> {
+    receiver: Receiver<P::Solution>,
+    shared: Arc<Shared<P::Posibility>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl<P> ParallelSolutions<P>
+where
+    P: Problem + Clone + Send + 'static,
+    P::Posibility: Send + 'static,
+    P::Solution: Send + 'static,
+{
+    /// Searches with one worker per available core.
+    pub fn new(init: P) -> Self {
+        let num_workers = available_parallelism().map_or(1, NonZeroUsize::get);
+        Self::with_num_workers(init, num_workers)
+    }
+
+    /// Searches with `num_workers` threads. Panics if `num_workers` is zero.
+    pub fn with_num_workers(init: P, num_workers: usize) -> Self {
+        assert!(num_workers > 0, "At least one worker is required.");
+        let mut possibilities = Vec::new();
+        init.extend_possibilities(&mut possibilities, &[]);
+        // Reverse, so the first possibility is the first job to be popped from the queue.
+        let jobs = possibilities
+            .into_iter()
+            .rev()
+            .map(|pos| vec![pos])
+            .collect();
+        let shared = Arc::new(Shared::new(jobs, num_workers));
+        let (sender, receiver) = channel();
+        let workers = (0..num_workers)
+            .map(|_| {
+                let init = init.clone();
+                let shared = shared.clone();
+                let sender = sender.clone();
+                thread::spawn(move || work(init, &shared, &sender))
+            })
+            .collect();
+        Self {
+            receiver,
+            shared,
+            workers,
+        }
+    }
+}
+
+impl<P: Problem> ParallelSolutions<P> {
+    /// Waits for all workers to finish and forwards their panics, if any.
+    fn join_workers(&mut self) {
+        for worker in self.workers.drain(..) {
+            if let Err(panic) = worker.join() {
+                panic::resume_unwind(panic)
+            }
+        }
+    }
+}
+
+impl<P: Problem> Iterator for ParallelSolutions<P> {
+    type Item = P::Solution;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.receiver.recv() {
+            Ok(solution) => Some(solution),
+            // All workers are done and dropped their senders.
+            Err(_) => {
+                self.join_workers();
+                None
+            }
+        }
+    }
+}
+
+impl<P: Problem> Drop for ParallelSolutions<P> {
+    fn drop(&mut self) {
+        self.shared.stop();
+        // Do not panic while panicking
+        if !thread::panicking() {
+            self.join_workers();
+        }
+    }
+}
+
+/// State shared between all the workers.
+struct Shared<D> {
+    /// Each job is the history leading to the root of a subtree which is yet to be explored.
+    jobs: Mutex<Vec<Vec<D>>>,
+    /// Signaled when a job is added, or the search is stopped.
+    changed: Condvar,
+    /// Number of workers waiting for a job. Only modified while holding the lock on `jobs`, but
+    /// read without it by busy workers, in order to decide whether to give away work.
+    num_idle: AtomicUsize,
+    num_workers: usize,
+    /// Set once all work is done, the consumer lost interest or a worker panicked.
+    stopped: AtomicBool,
+}
+
+impl<D> Shared<D> {
+    fn new(jobs: Vec<Vec<D>>, num_workers: usize) -> Self {
+        Self {
+            jobs: Mutex::new(jobs),
+            changed: Condvar::new(),
+            num_idle: AtomicUsize::new(0),
+            num_workers,
+            stopped: AtomicBool::new(false),
+        }
+    }
+
+    /// Blocks until there is a job for the calling worker. `None` if the search is over.
+    fn next_job(&self) -> Option<Vec<D>> {
+        let mut jobs = self.jobs.lock().unwrap();
+        loop {
+            if self.is_stopped() {
+                return None;
+            }
+            if let Some(job) = jobs.pop() {
+                return Some(job);
+            }
+            // Only busy workers can create new jobs. If we are the last one to run out of work, we
+            // are done.
+            if self.num_idle.fetch_add(1, Ordering::SeqCst) + 1 == self.num_workers {
+                self.stopped.store(true, Ordering::SeqCst);
+                self.changed.notify_all();
+                return None;
+            }
+            jobs = self.changed.wait(jobs).unwrap();
+            self.num_idle.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    fn is_hungry(&self) -> bool {
+        self.num_idle.load(Ordering::Relaxed) != 0
+    }
+
+    fn add_job(&self, job: Vec<D>) {
+        self.jobs.lock().unwrap().push(job);
+        self.changed.notify_one();
+    }
+
+    fn is_stopped(&self) -> bool {
+        self.stopped.load(Ordering::Relaxed)
+    }
+
+    fn stop(&self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // Hold the lock while notifying, so no worker can miss the signal between checking the flag
+        // and starting to wait. The lock is never held while executing code of the problem, so it
+        // can only be poisoned by a bug in this module.
+        let _jobs = self
+            .jobs
+            .lock()
+            .unwrap_or_else(|poison| poison.into_inner());
+        self.changed.notify_all();
+    }
+}
+
+/// Stops the search, if the worker owning it unwinds. Otherwise the remaining workers would wait
+/// forever for the panicking one to share its work.
+struct StopOnPanic<'a, D>(&'a Shared<D>);
+
+impl<D> Drop for StopOnPanic<'_, D> {
+    fn drop(&mut self) {
+        if thread::panicking() {
+            self.0.stop();
+        }
+    }
+}
+
+fn work<P: Problem + Clone>(init: P, shared: &Shared<P::Posibility>, sender: &Sender<P::Solution>) {
+    let _guard = StopOnPanic(shared);
+    while let Some(job) = shared.next_job() {
+        let mut solutions = Solutions::from_job(init.clone(), &job);
+        while let Some(candidate) = solutions.open.pop() {
+            if shared.is_stopped() {
+                return;
+            }
+            if let Some(solution) = solutions.visit(candidate) {
+                if sender.send(solution).is_err() {
+                    // Iterator has been dropped
+                    return;
+                }
+            }
+            if shared.is_hungry() && solutions.open.len() > 1 {
+                shared.add_job(solutions.split_off_shallowest());
+            }
+        }
+    }
+}
+
+impl<P: Problem> Solutions<P> {
+    /// Removes the shallowest candidate from the open list and returns it as a job, i.e. together
+    /// with the decisions leading up to it.
+    fn split_off_shallowest(&mut self) -> Vec<P::Posibility> {
+        let Candidate { count, possibility } = self.open.remove(0);
+        // All decisions up to the parent of a candidate in the open list are still part of the
+        // history, since we did not backtrack beyond it yet.
+        let mut job = self.history[..count - 1].to_vec();
+        job.push(possibility);
+        job
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_problems::Queens, Solutions};
+
+    use super::ParallelSolutions;
+
+    #[test]
+    fn find_same_solutions_as_sequential_search() {
+        let mut expected = Solutions::new(Queens::new(8)).collect::<Vec<_>>();
+        expected.sort();
+
+        let mut actual = ParallelSolutions::with_num_workers(Queens::new(8), 4).collect::<Vec<_>>();
+        actual.sort();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn stop_early() {
+        let mut solutions = ParallelSolutions::with_num_workers(Queens::new(10), 4);
+
+        assert!(solutions.next().is_some());
+        // Dropping must not block until all solutions have been found.
+        drop(solutions);
+    }
+}
@@ -0,0 +1,201 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{Candidate, Problem, Stats};
+
+/// Decides which states [`Snapshots`] keeps copies of.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SnapshotPolicy {
+    /// Keep a copy of each state with children. No decision is ever replayed.
+    EveryNode,
+    /// Only keep copies of states whose depth is a multiple of `k`. Getting to any other state
+    /// replays at most `k - 1` decisions. Larger values trade time for memory.
+    EveryKLevels(usize),
+}
+
+impl SnapshotPolicy {
+    fn k(self) -> usize {
+        match self {
+            SnapshotPolicy::EveryNode => 1,
+            SnapshotPolicy::EveryKLevels(k) => k,
+        }
+    }
+}
+
+/// An iterator finding the same solutions in the same order as [`crate::Solutions`] with its
+/// default [`crate::VisitOrder`], but never calling [`Problem::undo`]. Useful for problems whose
+/// state is hard to roll back.
+///
+/// Instead copies of the states along the current path are kept, as determined by the
+/// [`SnapshotPolicy`]. To get to the parent of the next candidate, the deepest copy of one of its
+/// ancestors is cloned, and the decisions in between are replayed using [`Problem::what_if`]. If
+/// the next candidate is a child of the current node, the current state is used directly.
+/// [`Stats::backtracks`] counts the decisions taken back by discarding states.
+pub struct Snapshots<P: Problem> {
+    decisions: Vec<P::Posibility>,
+    open: Vec<Candidate<P::Posibility>>,
+    history: Vec<P::Posibility>,
+    current: P,
+    /// Copies of states along the current path, together with their depth. Ordered from the root to
+    /// the deepest one.
+    snapshots: Vec<(usize, P)>,
+    k: usize,
+    stats: Stats,
+}
+
+impl<P: Problem + Clone> Snapshots<P> {
+    /// Panics if the policy is [`SnapshotPolicy::EveryKLevels`] with `k` equal to zero.
+    pub fn new(init: P, policy: SnapshotPolicy) -> Self {
+        let k = policy.k();
+        assert!(k > 0, "Snapshots must be taken at least every level.");
+        let mut decisions = Vec::new();
+        init.extend_possibilities(&mut decisions, &[]);
+        let open: Vec<_> = decisions
+            .iter()
+            .rev()
+            .map(|&possibility| Candidate {
+                count: 1,
+                possibility,
+            })
+            .collect();
+        let stats = Stats {
+            max_open: open.len(),
+            ..Stats::default()
+        };
+        Self {
+            decisions,
+            open,
+            history: Vec::new(),
+            snapshots: vec![(0, init.clone())],
+            current: init,
+            k,
+            stats,
+        }
+    }
+
+    /// Counters describing the work done by the search so far.
+    pub fn stats(&self) -> &Stats {
+        &self.stats
+    }
+
+    /// Number of states currently kept as copies, including the initial one.
+    pub fn num_snapshots(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    /// Changes the current state to the one of the parent of the candidate with depth `count`.
+    fn restore(&mut self, count: usize) {
+        let parent = count - 1;
+        if self.history.len() == parent {
+            return;
+        }
+        self.stats.backtracks += (self.history.len() - parent) as u64;
+        self.history.truncate(parent);
+        while self
+            .snapshots
+            .last()
+            .is_some_and(|&(depth, _)| depth > parent)
+        {
+            self.snapshots.pop();
+        }
+        // The initial state is never discarded.
+        let (depth, snapshot) = self.snapshots.last().unwrap();
+        self.current = snapshot.clone();
+        for &decision in &self.history[*depth..] {
+            self.current.what_if(decision);
+        }
+    }
+}
+
+impl<P: Problem + Clone> Iterator for Snapshots<P> {
+    type Item = P::Solution;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(Candidate { count, possibility }) = self.open.pop() {
+            self.restore(count);
+            self.current.what_if(possibility);
+            self.history.push(possibility);
+            self.stats.nodes += 1;
+            self.stats.max_depth = self.stats.max_depth.max(count);
+
+            if let Some(solution) = self.current.is_solution(&self.history) {
+                self.stats.solutions += 1;
+                return Some(solution);
+            }
+
+            self.decisions.clear();
+            self.current
+                .extend_possibilities(&mut self.decisions, &self.history);
+            if self.decisions.is_empty() {
+                self.stats.dead_ends += 1;
+                continue;
+            }
+            // Only states with children are ever returned to.
+            if count % self.k == 0 {
+                self.snapshots.push((count, self.current.clone()));
+            }
+            // Reverse, so the first possibility is popped first.
+            self.open
+                .extend(self.decisions.iter().rev().map(|&possibility| Candidate {
+                    count: count + 1,
+                    possibility,
+                }));
+            self.stats.max_open = self.stats.max_open.max(self.open.len());
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_problems::Queens, Problem, Solutions};
+
+    use super::{SnapshotPolicy, Snapshots};
+
+    /// Queens, but without the ability to undo decisions.
+    #[derive(Clone)]
+    struct Irreversible(Queens);
+
+    impl Problem for Irreversible {
+        type Posibility = u32;
+        type Solution = Vec<u32>;
+
+        fn extend_possibilities(&self, possibilities: &mut Vec<u32>, history: &[u32]) {
+            self.0.extend_possibilities(possibilities, history)
+        }
+
+        fn undo(&mut self, _last: &u32, _history: &[u32]) {
+            panic!("Undo must not be called.")
+        }
+
+        fn what_if(&mut self, decision: u32) {
+            self.0.what_if(decision)
+        }
+
+        fn is_solution(&self, history: &[u32]) -> Option<Vec<u32>> {
+            self.0.is_solution(history)
+        }
+    }
+
+    #[test]
+    fn same_solutions_in_same_order_as_backtracking() {
+        let expected = Solutions::new(Queens::new(6)).collect::<Vec<_>>();
+        for policy in [
+            SnapshotPolicy::EveryNode,
+            SnapshotPolicy::EveryKLevels(2),
+            SnapshotPolicy::EveryKLevels(4),
+            SnapshotPolicy::EveryKLevels(10),
+        ] {
+            let actual = Snapshots::new(Irreversible(Queens::new(6)), policy).collect::<Vec<_>>();
+            assert_eq!(expected, actual, "{policy:?}");
+        }
+    }
+
+    #[test]
+    fn keep_snapshots_along_current_path_only() {
+        let mut snapshots = Snapshots::new(Queens::new(8), SnapshotPolicy::EveryKLevels(3));
+        snapshots.next().unwrap();
+
+        // The initial state and the ancestors of the solution at depth three and six.
+        assert_eq!(3, snapshots.num_snapshots());
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{Observer, Problem, Solutions};
+
+/// What happened during a single call to [`Solutions::step`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step<D, S> {
+    /// Entered a node reached by the decision and put its children into the open list.
+    Descend(D),
+    /// Took back the decision, in order to get to the parent of the next candidate.
+    Backtrack(D),
+    /// Entered a node which is no solution and has no children.
+    DeadEnd,
+    /// Entered a node whose children are not explored, because its state has been found in the
+    /// transposition table or because of the depth limit.
+    Pruned,
+    /// Entered a node which is a solution.
+    Solution(S),
+    /// There are no more candidates to explore.
+    Exhausted,
+}
+
+impl<P: Problem, O: Observer<P>> Solutions<P, O> {
+    /// Advances the search by exactly one node, either by taking back a single decision or by
+    /// entering the next candidate. Use this instead of [`Iterator::next`] if you want to follow
+    /// the search as it happens, e.g. in order to visualize it. Each node entered is reported as
+    /// exactly one of [`Step::Descend`], [`Step::DeadEnd`], [`Step::Pruned`] or [`Step::Solution`].
+    /// The decision leading to it is the last element of [`Self::history`].
+    ///
+    /// Deadlines, node budgets and cancellation flags are not checked.
+    pub fn step(&mut self) -> Step<P::Posibility, P::Solution> {
+        let Some(&candidate) = self.open.last() else {
+            return Step::Exhausted;
+        };
+        // Is the candidate a child of the current node?
+        if self.history.len() >= candidate.count {
+            return Step::Backtrack(self.backtrack());
+        }
+        self.open.pop();
+        self.enter(candidate);
+        self.evaluate()
+    }
+
+    /// Decisions leading from the initial state to the current one.
+    pub fn history(&self) -> &[P::Posibility] {
+        &self.history
+    }
+
+    /// The problem in its current state.
+    pub fn problem(&self) -> &P {
+        &self.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_problems::Queens, Solutions};
+
+    use super::Step;
+
+    #[test]
+    fn step_through_four_queens() {
+        let mut solutions = Solutions::new(Queens::new(4));
+
+        assert_eq!(Step::Descend(0), solutions.step());
+        assert_eq!(&[0], solutions.history());
+        assert_eq!(Step::DeadEnd, solutions.step());
+        assert_eq!(&[0, 2], solutions.history());
+        assert_eq!(Step::Backtrack(2), solutions.step());
+        assert_eq!(&[0], solutions.history());
+
+        let mut steps = Vec::new();
+        loop {
+            let step = solutions.step();
+            if step == Step::Exhausted {
+                break;
+            }
+            steps.push(step);
+        }
+        let num_solutions = steps
+            .iter()
+            .filter(|step| matches!(step, Step::Solution(_)))
+            .count();
+        let num_backtracks = steps
+            .iter()
+            .filter(|step| matches!(step, Step::Backtrack(_)))
+            .count();
+        assert_eq!(2, num_solutions);
+        assert_eq!(solutions.stats().backtracks, num_backtracks as u64 + 1);
+    }
+}
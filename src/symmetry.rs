@@ -0,0 +1,147 @@
+use alloc::vec::Vec;
+
+use crate::{Problem, Solutions, Stats};
+
+/// A problem whose solutions come in groups which are equivalent under some symmetry. E.g. any
+/// solution of the N-Queens problem stays a solution if the board is rotated or mirrored. Used by
+/// [`Canonical`] to yield only one representative of each group.
+pub trait Symmetric: Problem
+where
+    Self::Solution: Ord,
+{
+    /// Images of `solution` under all transformations of the symmetry group, including the
+    /// identity. The smallest image is the canonical representative.
+    fn images(&self, solution: &Self::Solution) -> Vec<Self::Solution>;
+
+    /// `true` if no canonical solution can be reached by starting with `decision`. Allows
+    /// [`Canonical`] to skip mirrored subtrees at the root of the search tree. It is always correct
+    /// to return `false`, which is the default.
+    fn is_redundant_root(&self, decision: &Self::Posibility) -> bool {
+        let _ = decision;
+        false
+    }
+}
+
+/// Iterator yielding only the canonical representative of each group of symmetric solutions.
+///
+/// Solutions are not remembered, instead each solution is compared with its own images. So this
+/// does not require additional memory, but each solution is still found (and discarded) unless
+/// its subtree is pruned by [`Symmetric::is_redundant_root`].
+pub struct Canonical<P: Problem> {
+    solutions: Solutions<P>,
+}
+
+impl<P> Canonical<P>
+where
+    P: Symmetric,
+    P::Solution: Ord,
+{
+    pub fn new(init: P) -> Self {
+        let mut solutions = Solutions::new(init);
+        let current = &solutions.current;
+        solutions
+            .open
+            .retain(|candidate| !current.is_redundant_root(&candidate.possibility));
+        Self { solutions }
+    }
+
+    /// Yields each canonical solution together with the number of distinct solutions it stands for.
+    pub fn with_orbit_size(self) -> WithOrbitSize<P> {
+        WithOrbitSize(self)
+    }
+
+    /// Counters describing the work done by the search so far.
+    pub fn stats(&self) -> &Stats {
+        self.solutions.stats()
+    }
+
+    /// Next canonical solution together with the number of distinct solutions it stands for.
+    fn next_with_orbit_size(&mut self) -> Option<(P::Solution, usize)> {
+        while let Some(solution) = self.solutions.next() {
+            let mut images = self.solutions.current.images(&solution);
+            images.sort_unstable();
+            images.dedup();
+            if images.first() == Some(&solution) {
+                return Some((solution, images.len()));
+            }
+        }
+        None
+    }
+}
+
+impl<P> Iterator for Canonical<P>
+where
+    P: Symmetric,
+    P::Solution: Ord,
+{
+    type Item = P::Solution;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_orbit_size().map(|(solution, _)| solution)
+    }
+}
+
+/// Iterator yielding canonical solutions together with the number of distinct solutions they stand
+/// for. See [`Canonical::with_orbit_size`].
+pub struct WithOrbitSize<P: Problem>(Canonical<P>);
+
+impl<P> Iterator for WithOrbitSize<P>
+where
+    P: Symmetric,
+    P::Solution: Ord,
+{
+    type Item = (P::Solution, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next_with_orbit_size()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_problems::Queens;
+
+    use super::{Canonical, Symmetric};
+
+    impl Symmetric for Queens {
+        fn images(&self, solution: &Vec<u32>) -> Vec<Vec<u32>> {
+            let m = solution.len() as u32 - 1;
+            // Each bit of `symmetry` stands for mirroring columns, mirroring rows or transposing.
+            // Together these generate all rotations and reflections of the board.
+            (0..8)
+                .map(|symmetry| {
+                    let mut image = vec![0; solution.len()];
+                    for (row, &column) in solution.iter().enumerate() {
+                        let (mut row, mut column) = (row as u32, column);
+                        if symmetry & 1 != 0 {
+                            column = m - column;
+                        }
+                        if symmetry & 2 != 0 {
+                            row = m - row;
+                        }
+                        if symmetry & 4 != 0 {
+                            (row, column) = (column, row);
+                        }
+                        image[row as usize] = column;
+                    }
+                    image
+                })
+                .collect()
+        }
+
+        fn is_redundant_root(&self, column: &u32) -> bool {
+            // Mirroring horizontally would yield a smaller first column.
+            2 * column > self.n() - 1
+        }
+    }
+
+    #[test]
+    fn fundamental_solutions_of_eight_queens() {
+        let solutions = Canonical::new(Queens::new(8))
+            .with_orbit_size()
+            .collect::<Vec<_>>();
+
+        assert_eq!(12, solutions.len());
+        assert_eq!(92, solutions.iter().map(|(_, size)| size).sum::<usize>());
+    }
+}
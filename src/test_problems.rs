@@ -0,0 +1,113 @@
+//! Small problems shared between the unit tests of this crate.
+
+use crate::Problem;
+
+/// Place `n` queens on a `n` by `n` chessboard, so that no queen threatens another. Queens are
+/// placed row by row, the possibility is the column of the queen in the next row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Queens {
+    n: u32,
+}
+
+impl Queens {
+    pub fn new(n: u32) -> Self {
+        Self { n }
+    }
+
+    pub fn n(&self) -> u32 {
+        self.n
+    }
+}
+
+impl Problem for Queens {
+    type Posibility = u32;
+    type Solution = Vec<u32>;
+
+    fn extend_possibilities(&self, possibilities: &mut Vec<u32>, history: &[u32]) {
+        if history.len() == self.n as usize {
+            return;
+        }
+        let row = history.len();
+        possibilities.extend((0..self.n).filter(|&column| {
+            history
+                .iter()
+                .enumerate()
+                .all(|(other_row, &other_column)| {
+                    column != other_column
+                        && (row - other_row) as u32 != column.abs_diff(other_column)
+                })
+        }));
+    }
+
+    fn undo(&mut self, _last: &u32, _history: &[u32]) {}
+
+    fn what_if(&mut self, _decision: u32) {}
+
+    fn is_solution(&self, history: &[u32]) -> Option<Vec<u32>> {
+        (history.len() == self.n as usize).then(|| history.to_vec())
+    }
+}
+
+/// Find sequences of steps, either one or two long, which add up to a given length.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Stairs {
+    remaining: u32,
+}
+
+impl Stairs {
+    pub fn new(length: u32) -> Self {
+        Self { remaining: length }
+    }
+}
+
+impl Problem for Stairs {
+    type Posibility = u32;
+    type Solution = Vec<u32>;
+
+    fn extend_possibilities(&self, possibilities: &mut Vec<u32>, _history: &[u32]) {
+        possibilities.extend([1, 2].into_iter().filter(|&step| step <= self.remaining));
+    }
+
+    fn undo(&mut self, last: &u32, _history: &[u32]) {
+        self.remaining += last;
+    }
+
+    fn what_if(&mut self, decision: u32) {
+        self.remaining -= decision;
+    }
+
+    fn is_solution(&self, history: &[u32]) -> Option<Vec<u32>> {
+        (self.remaining == 0).then(|| history.to_vec())
+    }
+}
+
+/// Choose a non-empty subset of `0..n`. Elements are chosen in ascending order, so each subset is
+/// reached exactly once. Every node is a solution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Subsets {
+    n: u32,
+}
+
+impl Subsets {
+    pub fn new(n: u32) -> Self {
+        Self { n }
+    }
+}
+
+impl Problem for Subsets {
+    type Posibility = u32;
+    type Solution = Vec<u32>;
+
+    fn extend_possibilities(&self, possibilities: &mut Vec<u32>, history: &[u32]) {
+        let next = history.last().map_or(0, |last| last + 1);
+        possibilities.extend(next..self.n);
+    }
+
+    fn undo(&mut self, _last: &u32, _history: &[u32]) {}
+
+    fn what_if(&mut self, _decision: u32) {}
+
+    fn is_solution(&self, history: &[u32]) -> Option<Vec<u32>> {
+        Some(history.to_vec())
+    }
+}
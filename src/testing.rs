@@ -0,0 +1,100 @@
+//! Helpers for testing implementations of [`Problem`]. Requires the `testing` feature.
+
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use crate::{Problem, SearchResult, Solutions};
+
+/// Bounds the part of the search tree explored by [`cross_check`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Limit {
+    /// Explore the entire tree. Only use this for problems with a finite search tree.
+    None,
+    /// Do not consider histories with more than this many decisions.
+    Depth(usize),
+    /// Only enter this many nodes, in the order [`Solutions`] visits them.
+    Nodes(u64),
+}
+
+/// Searches `problem` with [`Solutions`] and with a naive recursive search, which clones the
+/// problem for each node rather than using [`Problem::undo`]. Panics if the two do not find the
+/// same solutions, ignoring their order. Returns the number of solutions found.
+///
+/// Any difference points to an incorrect implementation of [`Problem::undo`], or to a state which
+/// is not fully captured by [`Clone`].
+pub fn cross_check<P>(problem: P, limit: Limit) -> usize
+where
+    P: Problem + Clone,
+    P::Solution: Ord + Debug,
+{
+    let mut naive = Vec::new();
+    let mut num_nodes = 0;
+    search_naive(&problem, &mut Vec::new(), limit, &mut num_nodes, &mut naive);
+
+    let mut solutions = Solutions::new(problem);
+    solutions = match limit {
+        Limit::None => solutions,
+        Limit::Depth(max_depth) => solutions.with_max_depth(max_depth),
+        Limit::Nodes(max_nodes) => solutions.with_node_budget(max_nodes),
+    };
+    let mut backtracked = Vec::new();
+    while let SearchResult::Solution(solution) = solutions.next_solution() {
+        backtracked.push(solution);
+    }
+
+    naive.sort();
+    backtracked.sort();
+    assert_eq!(
+        naive, backtracked,
+        "Naive search (left) and backtracking (right) found different solutions."
+    );
+    backtracked.len()
+}
+
+/// Depth first search, visiting the children of each node in the same order as [`Solutions`] does.
+fn search_naive<P: Problem + Clone>(
+    problem: &P,
+    history: &mut Vec<P::Posibility>,
+    limit: Limit,
+    num_nodes: &mut u64,
+    solutions: &mut Vec<P::Solution>,
+) {
+    if matches!(limit, Limit::Depth(max_depth) if history.len() >= max_depth) {
+        return;
+    }
+    let mut decisions = Vec::new();
+    problem.extend_possibilities(&mut decisions, history);
+    for &decision in &decisions {
+        if matches!(limit, Limit::Nodes(max_nodes) if *num_nodes >= max_nodes) {
+            return;
+        }
+        *num_nodes += 1;
+        let mut child = problem.clone();
+        child.what_if(decision);
+        history.push(decision);
+        if let Some(solution) = child.is_solution(history) {
+            solutions.push(solution);
+        } else {
+            search_naive(&child, history, limit, num_nodes, solutions);
+        }
+        history.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_problems::{Queens, Stairs};
+
+    use super::{cross_check, Limit};
+
+    #[test]
+    fn cross_check_test_problems() {
+        assert_eq!(4, cross_check(Queens::new(6), Limit::None));
+        assert_eq!(0, cross_check(Queens::new(6), Limit::Depth(5)));
+        assert_eq!(89, cross_check(Stairs::new(10), Limit::None));
+        assert_eq!(16, cross_check(Stairs::new(10), Limit::Depth(6)));
+        for max_nodes in 0..40 {
+            cross_check(Stairs::new(6), Limit::Nodes(max_nodes));
+        }
+    }
+}
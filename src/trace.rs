@@ -0,0 +1,231 @@
+use std::{
+    fmt::Debug,
+    io::{self, Write},
+};
+
+use crate::{Observer, Problem};
+
+/// Observer recording the search tree explored by [`crate::Solutions`], so it can be rendered
+/// using Graphviz. Each node is recorded with its parent, the decision leading to it and what
+/// became of it.
+///
+/// The root of the recorded tree is the parent of the first node entered. Usually this is the
+/// initial state, but not if the search starts below it, e.g. with
+/// [`crate::Solutions::from_history`]. Nodes shallower than the first one entered are attached to
+/// the root, too.
+///
+/// ```
+/// # use backtracking::{Problem, Solutions, Trace};
+/// # fn write_tree<P: Problem>(problem: P) -> std::io::Result<()>
+/// # where P::Posibility: std::fmt::Debug {
+/// let mut solutions = Solutions::new(problem).with_observer(Trace::debug(1000));
+/// solutions.by_ref().count();
+/// solutions.observer().write_dot(&mut std::fs::File::create("tree.dot")?)?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Trace<F> {
+    /// All recorded nodes, except the root. The id of a node is its index plus one. The root has id
+    /// `0`.
+    nodes: Vec<TraceNode>,
+    /// Maximum number of nodes to record, not counting the root.
+    limit: usize,
+    /// Ids of the nodes on the path from the root to the current node. `None` for nodes which have
+    /// not been recorded due to the limit.
+    path: Vec<Option<usize>>,
+    /// Depth of the node the root stands for. Known once the first node has been entered.
+    base: Option<usize>,
+    /// `true` if nodes have been omitted due to the limit.
+    truncated: bool,
+    /// Formats the decision leading to a node as the label of its edge.
+    label: F,
+}
+
+struct TraceNode {
+    parent: usize,
+    label: String,
+    outcome: Outcome,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    /// Node with children
+    Inner,
+    DeadEnd,
+    Solution,
+    Pruned,
+}
+
+impl<D: Debug> Trace<fn(&D) -> String> {
+    /// Records up to `limit` nodes, labeling edges with the [`Debug`] representation of the
+    /// decisions.
+    pub fn debug(limit: usize) -> Self {
+        Self::new(limit, |decision| format!("{decision:?}"))
+    }
+}
+
+impl<F> Trace<F> {
+    /// Records up to `limit` nodes, labeling edges with the text `label` returns for the decision.
+    pub fn new(limit: usize, label: F) -> Self {
+        Self {
+            nodes: Vec::new(),
+            limit,
+            path: vec![Some(0)],
+            base: None,
+            truncated: false,
+            label,
+        }
+    }
+
+    /// Number of nodes recorded, not counting the root.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// `true` if nodes have been omitted, because the limit has been reached.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Writes the recorded tree in the DOT language. Solutions are drawn green, dead ends red and
+    /// pruned nodes gray.
+    pub fn write_dot(&self, out: &mut impl Write) -> io::Result<()> {
+        writeln!(out, "digraph search_tree {{")?;
+        writeln!(
+            out,
+            "    node [shape=circle, label=\"\", style=filled, fillcolor=white];"
+        )?;
+        writeln!(out, "    0 [shape=doublecircle];")?;
+        for (index, node) in self.nodes.iter().enumerate() {
+            let id = index + 1;
+            let color = match node.outcome {
+                Outcome::Inner => None,
+                Outcome::DeadEnd => Some("red"),
+                Outcome::Solution => Some("green"),
+                Outcome::Pruned => Some("gray"),
+            };
+            if let Some(color) = color {
+                writeln!(out, "    {id} [fillcolor={color}];")?;
+            }
+            writeln!(
+                out,
+                "    {} -> {id} [label=\"{}\"];",
+                node.parent,
+                escape(&node.label)
+            )?;
+        }
+        if self.truncated {
+            writeln!(
+                out,
+                "    truncated [shape=plaintext, label=\"node limit reached\"];"
+            )?;
+        }
+        writeln!(out, "}}")
+    }
+
+    fn set_outcome(&mut self, outcome: Outcome) {
+        if let Some(Some(id)) = self.path.last() {
+            self.nodes[id - 1].outcome = outcome;
+        }
+    }
+}
+
+impl<P, F> Observer<P> for Trace<F>
+where
+    P: Problem,
+    F: FnMut(&P::Posibility) -> String,
+{
+    fn enter(&mut self, _problem: &P, history: &[P::Posibility]) {
+        let depth = history.len();
+        let base = *self.base.get_or_insert(depth - 1);
+        // Forget about the nodes we backtracked from. Afterwards the last element is the parent.
+        self.path.truncate((depth - 1).saturating_sub(base) + 1);
+        let id = match self.path.last().copied().flatten() {
+            Some(parent) if self.nodes.len() < self.limit => {
+                self.nodes.push(TraceNode {
+                    parent,
+                    label: (self.label)(history.last().unwrap()),
+                    outcome: Outcome::Inner,
+                });
+                Some(self.nodes.len())
+            }
+            Some(_) => {
+                self.truncated = true;
+                None
+            }
+            // Parent has not been recorded either
+            None => None,
+        };
+        self.path.push(id);
+    }
+
+    fn dead_end(&mut self, _history: &[P::Posibility]) {
+        self.set_outcome(Outcome::DeadEnd);
+    }
+
+    fn pruned(&mut self, _history: &[P::Posibility]) {
+        self.set_outcome(Outcome::Pruned);
+    }
+
+    fn solution(&mut self, _problem: &P, _history: &[P::Posibility]) {
+        self.set_outcome(Outcome::Solution);
+    }
+}
+
+/// Escapes text, so it can be used within a quoted DOT string.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        test_problems::{Queens, Subsets},
+        Solutions,
+    };
+
+    use super::Trace;
+
+    #[test]
+    fn dot_of_four_queens() {
+        let mut solutions = Solutions::new(Queens::new(4)).with_observer(Trace::debug(100));
+        solutions.by_ref().count();
+
+        let mut dot = Vec::new();
+        solutions.observer().write_dot(&mut dot).unwrap();
+        let dot = String::from_utf8(dot).unwrap();
+
+        assert!(dot.starts_with("digraph search_tree {\n"));
+        assert_eq!(16, dot.matches(" -> ").count());
+        assert_eq!(2, dot.matches("fillcolor=green").count());
+        assert_eq!(4, dot.matches("fillcolor=red").count());
+        assert!(dot.contains("    0 -> 1 [label=\"0\"];\n"));
+    }
+
+    #[test]
+    fn dot_of_subtree_below_history() {
+        let mut solutions =
+            Solutions::from_history(Subsets::new(4), &[0, 1]).with_observer(Trace::debug(100));
+        solutions.by_ref().count();
+
+        let mut dot = Vec::new();
+        solutions.observer().write_dot(&mut dot).unwrap();
+        let dot = String::from_utf8(dot).unwrap();
+
+        assert!(dot.contains("    0 -> 1 [label=\"2\"];\n"));
+        assert!(dot.contains("    0 -> 2 [label=\"3\"];\n"));
+    }
+
+    #[test]
+    fn stop_recording_at_limit() {
+        let mut solutions = Solutions::new(Queens::new(6)).with_observer(Trace::debug(10));
+        solutions.by_ref().count();
+
+        assert_eq!(10, solutions.observer().len());
+        assert!(solutions.observer().is_truncated());
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(feature = "std")]
+use alloc::{boxed::Box, collections::VecDeque};
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
+#[cfg(feature = "std")]
+use crate::{Observer, Problem, Solutions};
+
+/// A problem whose states can be identified by a key, independent of the history leading to them.
+/// Enables [`Solutions::with_transposition_table`].
+#[cfg(feature = "std")]
+pub trait StateKey: Problem {
+    /// Identifies the state of the problem. E.g. the digits in the cells of a sudoku puzzle, or the
+    /// set of positions occupied by queens.
+    type Key: Hash + Eq + Clone;
+
+    /// Key of the current state. Two states with equal keys must have the same solutions below
+    /// them.
+    fn state_key(&self) -> Self::Key;
+}
+
+#[cfg(feature = "std")]
+impl<P: Problem, O: Observer<P>> Solutions<P, O> {
+    /// Remembers the keys of states whose subtree has been fully explored and skips any candidate
+    /// leading to such a state again. This can cut down the search tree considerably for problems
+    /// which reach the same state along different histories. It also means, that each solution is
+    /// only found along one history.
+    ///
+    /// At most `capacity` keys are remembered. Once the table is full, the key inserted first is
+    /// evicted. Evicted states are explored again, should they be reached a second time.
+    ///
+    /// If combined with [`Self::with_max_depth`], states whose subtree has been cut off are not
+    /// remembered, since reaching them again at a lower depth may yield more solutions.
+    pub fn with_transposition_table(mut self, capacity: usize) -> Self
+    where
+        P: StateKey,
+        P::Key: Send + 'static,
+    {
+        self.transpositions = Some(Box::new(TranspositionTable::<P::Key>::new(capacity)));
+        self
+    }
+}
+
+/// Keys of the states which have been fully explored, without [`Solutions`] needing to know the
+/// type of the key.
+pub trait Transpositions<P> {
+    fn contains(&self, problem: &P) -> bool;
+
+    fn insert(&mut self, problem: &P);
+
+    /// Forgets all states, e.g. because a different problem is going to be searched.
+    fn clear(&mut self);
+}
+
+/// Set of keys with limited capacity, which evicts the oldest key if full.
+#[cfg(feature = "std")]
+struct TranspositionTable<K> {
+    keys: HashSet<K>,
+    /// Keys in order of insertion, so we know which one to evict.
+    queue: VecDeque<K>,
+    capacity: usize,
+}
+
+#[cfg(feature = "std")]
+impl<K> TranspositionTable<K> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            keys: HashSet::new(),
+            queue: VecDeque::new(),
+            capacity,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<P: StateKey> Transpositions<P> for TranspositionTable<P::Key> {
+    fn contains(&self, problem: &P) -> bool {
+        self.keys.contains(&problem.state_key())
+    }
+
+    fn insert(&mut self, problem: &P) {
+        if self.capacity == 0 {
+            return;
+        }
+        let key = problem.state_key();
+        if self.keys.contains(&key) {
+            return;
+        }
+        if self.queue.len() == self.capacity {
+            let oldest = self.queue.pop_front().unwrap();
+            self.keys.remove(&oldest);
+        }
+        self.queue.push_back(key.clone());
+        self.keys.insert(key);
+    }
+
+    fn clear(&mut self) {
+        self.keys.clear();
+        self.queue.clear();
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::{test_problems::Stairs, Solutions};
+
+    use super::StateKey;
+
+    impl StateKey for Stairs {
+        type Key = Stairs;
+
+        fn state_key(&self) -> Stairs {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn skip_explored_states() {
+        let mut exhaustive = Solutions::new(Stairs::new(10));
+        let mut with_table = Solutions::new(Stairs::new(10)).with_transposition_table(100);
+
+        assert_eq!(89, exhaustive.by_ref().count());
+        // All solutions end in the same state, so we only find the first one.
+        assert_eq!(1, with_table.by_ref().count());
+        assert!(with_table.stats().nodes < exhaustive.stats().nodes);
+        assert!(with_table.stats().pruned > 0);
+    }
+
+    #[test]
+    fn send_with_transposition_table() {
+        fn assert_send<T: Send>(_: &T) {}
+        assert_send(&Solutions::new(Stairs::new(10)).with_transposition_table(100));
+    }
+
+    #[test]
+    fn explore_evicted_states_again() {
+        let mut small = Solutions::new(Stairs::new(10)).with_transposition_table(1);
+        let mut large = Solutions::new(Stairs::new(10)).with_transposition_table(100);
+
+        let num_small = small.by_ref().count();
+        large.by_ref().count();
+
+        assert!(num_small > 1);
+        assert!(small.stats().nodes > large.stats().nodes);
+    }
+
+    #[test]
+    fn remember_only_states_which_have_not_been_cut_off() {
+        // Remaining length 2 is reached at depth 2 via `[1, 1]` first, where the depth limit cuts
+        // it off, and then at depth 1 via `[2]`, where it still leads to a solution.
+        let solutions = Solutions::new(Stairs::new(4))
+            .with_max_depth(2)
+            .with_transposition_table(100)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![vec![2, 2]], solutions);
+    }
+
+    #[test]
+    fn forget_explored_states_on_reset() {
+        let mut solutions = Solutions::new(Stairs::new(10)).with_transposition_table(100);
+        solutions.by_ref().count();
+
+        solutions.reset(Stairs::new(10));
+
+        assert_eq!(1, solutions.count());
+    }
+}